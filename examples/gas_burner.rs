@@ -1,12 +1,60 @@
 use fuzzy_logic_engine_rs::{
     fis::{FisType, FuzzyInferenceSystem},
+    fixed::{FixedFis, FixedRule, FixedVariable},
+    fixed_point::Q16,
     membership::MembershipKind as M,
     rule::{Connective, Rule},
     term::Term,
     variable::{LinguisticVariable, Range},
 };
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+// The same controller for an 8/16-bit MCU without FPU: no heap, Q16.16 integer arithmetic,
+// parameters converted at compile time
+const fn trapezoid(a: f64, b: f64, c: f64, d: f64) -> Option<M<Q16>> {
+    Some(M::Trapezoid {
+        a: Q16::from_f64(a),
+        b: Q16::from_f64(b),
+        c: Q16::from_f64(c),
+        d: Q16::from_f64(d),
+    })
+}
+
+const fn singleton(value: f64) -> Option<M<Q16>> {
+    Some(M::Singleton {
+        value: Q16::from_f64(value),
+    })
+}
+
+pub static BURNER: FixedFis<1, 1, 3, 3, 200, Q16> = FixedFis::new(
+    // OXI: low_flow, medium_flow, high_flow
+    [FixedVariable::new(
+        Range {
+            min: Q16::from_f64(0.0),
+            max: Q16::from_f64(3.0),
+        },
+        [
+            trapezoid(0.1, 1.0, 1.0, 1.5),
+            trapezoid(0.5, 1.5, 2.0, 3.0),
+            trapezoid(2.0, 2.5, 2.5, 3.0),
+        ],
+    )],
+    // POWER: LOW_POWER, MEDIUM_POWER, HIGH_FUN
+    [FixedVariable::new(
+        Range {
+            min: Q16::from_f64(0.0),
+            max: Q16::from_f64(100.0),
+        },
+        [singleton(20.0), singleton(40.0), singleton(70.0)],
+    )],
+    [
+        FixedRule::new([Some(0)], [Some(0)], Connective::And),
+        FixedRule::new([Some(1)], [Some(1)], Connective::And),
+        FixedRule::new([Some(2)], [Some(2)], Connective::Or),
+    ],
+);
+
+// Also loaded by the integration tests (tests/common), which compare it in f32 and Q16
+pub fn system() -> Result<FuzzyInferenceSystem, Box<dyn std::error::Error>> {
     let mut system = FuzzyInferenceSystem::new("Gas Burner Power");
 
     // Define output variable: Power
@@ -17,36 +65,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             max: 100.0,
         },
     );
-    // Crisp consequents approximated as trapezoids with flat membership
-    power.add_term(Term::new(
-        "LOW_POWER",
-        M::Trapezoid {
-            a: 0.0,
-            b: 20.0,
-            c: 20.0,
-            d: 30.0,
-        },
-    ));
-    power.add_term(Term::new(
-        "MEDIUM_POWER",
-        M::Trapezoid {
-            a: 25.0,
-            b: 25.0,
-            c: 55.0,
-            d: 65.0,
-        },
-    ));
-    // Functional consequent: approximate HIGH_FUN = OXI^2
-    // For simplicity, represent as a polygon mapping (0→0, 3→9)
-    power.add_term(Term::new(
-        "HIGH_FUN",
-        M::Trapezoid {
-            a: 40.0,
-            b: 60.0,
-            c: 80.0,
-            d: 100.0,
-        },
-    ));
+    // Crisp consequents: the centroid of fired singletons is their weighted average
+    power.add_term(Term::new("LOW_POWER", M::Singleton { value: 20.0 }))?;
+    power.add_term(Term::new("MEDIUM_POWER", M::Singleton { value: 40.0 }))?;
+    // Functional consequent HIGH_FUN = OXI^2 needs a Sugeno system (M::Linear),
+    // approximated here by the power it gives at high flow
+    power.add_term(Term::new("HIGH_FUN", M::Singleton { value: 70.0 }))?;
     system.add_output(power);
 
     // Define input variable: Oxygen flow (OXI)
@@ -59,7 +83,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 1.0,
             d: 1.5,
         },
-    ));
+    ))?;
     oxi.add_term(Term::new(
         "medium_flow",
         M::Trapezoid {
@@ -68,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 2.0,
             d: 3.0,
         },
-    ));
+    ))?;
     oxi.add_term(Term::new(
         "high_flow",
         M::Trapezoid {
@@ -77,7 +101,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 2.5,
             d: 3.0,
         },
-    ));
+    ))?;
     system.add_input(oxi);
 
     // Define rules
@@ -105,6 +129,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     system.set_rules(vec![r1, r2, r3]);
+    Ok(system)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let system = system()?;
 
     // Evaluate
     let oxi = 0.51;
@@ -113,8 +142,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let out = result.unwrap();
 
     println!("Inputs: oxi={:?} => Gas Burner Power ≈ {:?}", oxi, out);
-    assert!(out[0] > 17.0);
-    assert!(out[0] < 18.0);
+    // low_flow 0.41 / 0.9 and medium_flow 0.01: (20 · 0.4556 + 40 · 0.01) / 0.4656
+    assert!(out[0] > 20.4295);
+    assert!(out[0] < 20.4297);
 
     match system.compute_verbose(FisType::Mamdani, &inputs) {
         Ok(outputs) => {
@@ -124,5 +154,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Err(e) => eprintln!("compute_verbose() - Error: {}", e),
     }
+
+    // Fixed-point evaluation, e.g. with an ADC reading already scaled to Q16.16
+    let [power] = BURNER.compute(FisType::Mamdani, &[Q16::from_f64(oxi)])?;
+    println!("Q16.16: oxi={:?} => Gas Burner Power ≈ {}", oxi, power);
+    assert!(power > Q16::from_f64(20.428));
+    assert!(power < Q16::from_f64(20.431));
     Ok(())
 }
@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     decision.add_term(Term::new(
         "neutral",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     decision.add_term(Term::new(
         "build",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_output(decision);
 
     // Input: budget (0 = very low, 100 = very high)
@@ -58,7 +58,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     budget.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     budget.add_term(Term::new(
         "high",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(budget);
 
     // Input: internal expertise (0 = none, 10 = very strong)
@@ -92,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 4.0,
         },
-    ));
+    ))?;
     expertise.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 7.0,
         },
-    ));
+    ))?;
     expertise.add_term(Term::new(
         "high",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 10.0,
             c: 10.0,
         },
-    ));
+    ))?;
     system.add_input(expertise);
 
     // Input: urgency (0 = no rush, 10 = extremely urgent)
@@ -126,7 +126,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 4.0,
         },
-    ));
+    ))?;
     urgency.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -134,7 +134,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 7.0,
         },
-    ));
+    ))?;
     urgency.add_term(Term::new(
         "high",
         M::Triangle {
@@ -142,7 +142,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 10.0,
             c: 10.0,
         },
-    ));
+    ))?;
     system.add_input(urgency);
 
     // Input: vendor reliability (0 = poor, 10 = excellent)
@@ -160,7 +160,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 4.0,
         },
-    ));
+    ))?;
     vendor.add_term(Term::new(
         "average",
         M::Triangle {
@@ -168,7 +168,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 7.0,
         },
-    ));
+    ))?;
     vendor.add_term(Term::new(
         "excellent",
         M::Triangle {
@@ -176,7 +176,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 10.0,
             c: 10.0,
         },
-    ));
+    ))?;
     system.add_input(vendor);
 
     // Rules
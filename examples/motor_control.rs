@@ -6,7 +6,8 @@ use fuzzy_logic_engine_rs::{
     variable::{LinguisticVariable, Range},
 };
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+// Also loaded by the integration tests (tests/common), which compare it in f32
+pub fn system() -> Result<FuzzyInferenceSystem, Box<dyn std::error::Error>> {
     let mut system = FuzzyInferenceSystem::new("Motor Control");
 
     // Define output variable (motor speed)
@@ -25,7 +26,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 1500.0,
             d: 2000.0,
         },
-    ));
+    ))?;
     motor.add_term(Term::new(
         "slow",
         M::Trapezoid {
@@ -34,7 +35,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 800.0,
             d: 1200.0,
         },
-    ));
+    ))?;
     system.add_output(motor);
 
     // Define input variable: Temperature
@@ -53,7 +54,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 0.0,
             d: 20.0,
         },
-    ));
+    ))?;
     temp.add_term(Term::new(
         "hot",
         M::Trapezoid {
@@ -62,7 +63,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 80.0,
             d: 80.0,
         },
-    ));
+    ))?;
     system.add_input(temp);
 
     // Define input variable: Humidity
@@ -81,7 +82,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 20.0,
             d: 50.0,
         },
-    ));
+    ))?;
     hum.add_term(Term::new(
         "wet",
         M::Trapezoid {
@@ -90,7 +91,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 100.0,
             d: 100.0,
         },
-    ));
+    ))?;
     system.add_input(hum);
 
     // Rules
@@ -123,6 +124,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     system.set_rules(vec![r1, r2, r3, r4]);
+    Ok(system)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let system = system()?;
 
     // Evaluate
     let temp = 42.0;
@@ -138,6 +144,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     assert!(out[0] > 1480.0);
     assert!(out[0] < 1490.0);
 
+    // Control loop: compile once, then evaluate without allocating or looking up names
+    let compiled = system.compile(FisType::Mamdani)?;
+    let mut workspace = compiled.workspace();
+    let mut speed = [0.0];
+    for step in 0..10_000 {
+        let temp = 20.0 + (step % 400) as f64 * 0.1;
+        compiled.compute_into(&mut workspace, &[temp, hum], &mut speed)?;
+    }
+    compiled.compute_into(&mut workspace, &inputs, &mut speed)?;
+    assert_eq!(speed[0], out[0]);
+
     match system.compute_verbose(FisType::Mamdani, &inputs) {
         Ok(outputs) => {
             for out in outputs {
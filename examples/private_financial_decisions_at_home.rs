@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 15.0,
         },
-    ));
+    ))?;
     savings.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 20.0,
             c: 30.0,
         },
-    ));
+    ))?;
     savings.add_term(Term::new(
         "high",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 40.0,
             c: 50.0,
         },
-    ));
+    ))?;
     system.add_output(savings);
 
     // Input: income stability (0 = unstable, 10 = very stable)
@@ -58,7 +58,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 4.0,
         },
-    ));
+    ))?;
     stability.add_term(Term::new(
         "moderate",
         M::Triangle {
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 7.0,
         },
-    ));
+    ))?;
     stability.add_term(Term::new(
         "stable",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 10.0,
             c: 10.0,
         },
-    ));
+    ))?;
     system.add_input(stability);
 
     // Input: current expenses (% of income)
@@ -92,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 20.0,
             c: 40.0,
         },
-    ));
+    ))?;
     expenses.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     expenses.add_term(Term::new(
         "high",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 80.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(expenses);
 
     // Rules
@@ -119,6 +119,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             vec!["high".into()],
             Connective::And,
         ),
+        // If income is stable and expenses are medium -> save medium
+        Rule::new(
+            vec![Some("stable".into()), Some("medium".into())],
+            vec!["medium".into()],
+            Connective::And,
+        ),
         // If income is moderate and expenses are medium -> save medium
         Rule::new(
             vec![Some("moderate".into()), Some("medium".into())],
@@ -152,8 +158,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Inputs: stable_income={:?}, medium_expenses={:?} => Home finance advisor ≈ {:?}",
         stable_income, medium_expenses, out
     );
-    assert!(out[0] > 6.0);
-    assert!(out[0] < 7.0);
+    // stable 0.5, medium expenses 0.75: only "save medium" fires, clipped at 0.5
+    assert!(out[0] > 19.9);
+    assert!(out[0] < 20.1);
 
     match system.compute_verbose(FisType::Mamdani, &inputs) {
         Ok(outputs) => {
@@ -6,7 +6,8 @@ use fuzzy_logic_engine_rs::{
     variable::{LinguisticVariable, Range},
 };
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+// Also loaded by the integration tests (tests/common), which compare it in f32 and Q16
+pub fn system() -> Result<FuzzyInferenceSystem, Box<dyn std::error::Error>> {
     let mut system = FuzzyInferenceSystem::new("Fuzzy logic based TIP system");
 
     let mut tip = LinguisticVariable::new(
@@ -23,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 10.0,
         },
-    ));
+    ))?;
     tip.add_term(Term::new(
         "average",
         M::Triangle {
@@ -31,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 15.0,
             c: 20.0,
         },
-    ));
+    ))?;
     tip.add_term(Term::new(
         "generous",
         M::Triangle {
@@ -39,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 25.0,
             c: 30.0,
         },
-    ));
+    ))?;
     system.add_output(tip);
 
     let mut service = LinguisticVariable::new(
@@ -55,21 +56,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             sigma: 2.123,
             mu: 0.0,
         },
-    ));
+    ))?;
     service.add_term(Term::new(
         "normal",
         M::Gauss {
             sigma: 2.123,
             mu: 5.0,
         },
-    ));
+    ))?;
     service.add_term(Term::new(
         "excellent",
         M::Gauss {
             sigma: 2.123,
             mu: 10.0,
         },
-    ));
+    ))?;
     system.add_input(service);
 
     let mut food = LinguisticVariable::new(
@@ -87,7 +88,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 1.0,
             d: 3.0,
         },
-    ));
+    ))?;
     food.add_term(Term::new(
         "good",
         M::Trapezoid {
@@ -96,7 +97,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             c: 10.0,
             d: 10.0,
         },
-    ));
+    ))?;
     system.add_input(food);
 
     system.set_rules(vec![
@@ -116,7 +117,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Connective::And,
         ),
     ]);
+    Ok(system)
+}
 
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let system = system()?;
     let result = system.compute(FisType::Mamdani, &[7.892, 7.41])?;
     println!("{result:?}");
     assert!(result[0] > 18.0);
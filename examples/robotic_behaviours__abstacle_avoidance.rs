@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 20.0,
         },
-    ));
+    ))?;
     speed.add_term(Term::new(
         "slow",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 30.0,
             c: 50.0,
         },
-    ));
+    ))?;
     speed.add_term(Term::new(
         "fast",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 75.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_output(speed);
 
     // Input: distance to obstacle
@@ -58,7 +58,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))?;
     distance.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 160.0,
         },
-    ));
+    ))?;
     distance.add_term(Term::new(
         "far",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 200.0,
             c: 200.0,
         },
-    ));
+    ))?;
     system.add_input(distance);
 
     // Input: battery level
@@ -92,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     battery.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     battery.add_term(Term::new(
         "high",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(battery);
 
     // Rules
@@ -144,8 +144,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Inputs: distance_inp={:?}, battery_inp={:?} => Robot behaviour ≈ {:?}",
         distance_inp, battery_inp, out
     );
-    assert!(out[0] > 74.0);
-    assert!(out[0] < 75.0);
+    // only "fast" fires: the centroid of the clipped symmetric triangle is its peak
+    assert!(out[0] > 74.999);
+    assert!(out[0] < 75.001);
 
     match system.compute_verbose(FisType::Mamdani, &inputs) {
         Ok(outputs) => {
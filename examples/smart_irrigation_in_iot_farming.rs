@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 10.0,
         },
-    ));
+    ))?;
     irrigation.add_term(Term::new(
         "low",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 20.0,
             c: 35.0,
         },
-    ));
+    ))?;
     irrigation.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 55.0,
             c: 70.0,
         },
-    ));
+    ))?;
     irrigation.add_term(Term::new(
         "high",
         M::Triangle {
@@ -48,7 +48,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 85.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_output(irrigation);
 
     // Input: soil moisture (% volumetric water content)
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 30.0,
         },
-    ));
+    ))?;
     soil.add_term(Term::new(
         "optimal",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 45.0,
             c: 65.0,
         },
-    ));
+    ))?;
     soil.add_term(Term::new(
         "wet",
         M::Triangle {
@@ -82,7 +82,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(soil);
 
     // Input: rain forecast (mm expected in next 24h)
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 5.0,
         },
-    ));
+    ))?;
     rain.add_term(Term::new(
         "light",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 10.0,
             c: 20.0,
         },
-    ));
+    ))?;
     rain.add_term(Term::new(
         "heavy",
         M::Triangle {
@@ -116,7 +116,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 50.0,
         },
-    ));
+    ))?;
     system.add_input(rain);
 
     // Input: crop growth stage (0 = germination, 10 = harvest)
@@ -134,7 +134,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 1.5,
             c: 3.0,
         },
-    ));
+    ))?;
     stage.add_term(Term::new(
         "mid",
         M::Triangle {
@@ -142,7 +142,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 5.0,
             c: 7.0,
         },
-    ));
+    ))?;
     stage.add_term(Term::new(
         "late",
         M::Triangle {
@@ -150,7 +150,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 9.5,
             c: 10.0,
         },
-    ));
+    ))?;
     system.add_input(stage);
 
     // Rules:
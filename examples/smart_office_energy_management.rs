@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     hvac.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     hvac.add_term(Term::new(
         "high",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_output(hvac);
 
     // Input: occupancy (0 = empty, 100 = full)
@@ -58,7 +58,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     occupancy.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     occupancy.add_term(Term::new(
         "high",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(occupancy);
 
     // Input: outside temperature (°C, -10 to 40)
@@ -92,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: -10.0,
             c: 10.0,
         },
-    ));
+    ))?;
     temperature.add_term(Term::new(
         "mild",
         M::Triangle {
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 20.0,
             c: 25.0,
         },
-    ));
+    ))?;
     temperature.add_term(Term::new(
         "hot",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 40.0,
             c: 40.0,
         },
-    ));
+    ))?;
     system.add_input(temperature);
 
     // Input: energy price (0 = very cheap, 100 = very expensive)
@@ -126,7 +126,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 40.0,
         },
-    ));
+    ))?;
     price.add_term(Term::new(
         "medium",
         M::Triangle {
@@ -134,7 +134,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 50.0,
             c: 70.0,
         },
-    ));
+    ))?;
     price.add_term(Term::new(
         "high",
         M::Triangle {
@@ -142,7 +142,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))?;
     system.add_input(price);
 
     // Rules
@@ -176,7 +176,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example scenario: 70% occupancy, 28°C outside, price = 65
     let result = system.compute(FisType::Mamdani, &[70.0, 28.0, 65.0])?;
     println!("HVAC intensity decision: {:.2}%", result[0]);
-    assert!(result[0] > 86.0);
+    // the low price rule does not fire at 65, so it no longer pulls the result up
+    assert!(result[0] > 82.0);
+    assert!(result[0] < 83.0);
 
     Ok(())
 }
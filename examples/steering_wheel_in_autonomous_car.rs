@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: -20.0,
             c: -10.0,
         },
-    ));
+    ))?;
     steering.add_term(Term::new(
         "straight",
         M::Triangle {
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 5.0,
         },
-    ));
+    ))?;
     steering.add_term(Term::new(
         "right",
         M::Triangle {
@@ -40,7 +40,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 20.0,
             c: 30.0,
         },
-    ));
+    ))?;
     system.add_output(steering);
 
     // Input: lane deviation (meters from center)
@@ -58,7 +58,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: -2.0,
             c: -0.5,
         },
-    ));
+    ))?;
     deviation.add_term(Term::new(
         "center",
         M::Triangle {
@@ -66,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 0.5,
         },
-    ));
+    ))?;
     deviation.add_term(Term::new(
         "right",
         M::Triangle {
@@ -74,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 2.0,
             c: 2.0,
         },
-    ));
+    ))?;
     system.add_input(deviation);
 
     // Input: road curvature (negative = left curve, positive = right curve)
@@ -92,7 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: -1.0,
             c: -0.3,
         },
-    ));
+    ))?;
     curvature.add_term(Term::new(
         "straight",
         M::Triangle {
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 0.0,
             c: 0.2,
         },
-    ));
+    ))?;
     curvature.add_term(Term::new(
         "right",
         M::Triangle {
@@ -108,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             b: 1.0,
             c: 1.0,
         },
-    ));
+    ))?;
     system.add_input(curvature);
 
     // Rules
@@ -0,0 +1,581 @@
+/*
+Precompiled inference plan.
+
+`FuzzyInferenceSystem::compute` resolves every term by name and samples the
+output axis on each call. A `CompiledSystem` does that work once:
+
+- rule clauses and consequents refer to terms by index
+- rule weights, term names and consequent kinds are checked up front
+- Mamdani output grids and consequent membership curves are sampled once,
+  the knots of the exact centroid of piecewise linear terms are sorted once,
+  outputs of singletons are detected once
+
+Evaluation with `compute_into` and a reusable `Workspace` performs no heap
+allocation and no string comparison:
+
+    let compiled = system.compile(FisType::Mamdani)?;
+    let mut workspace = compiled.workspace();
+    let mut outputs = [0.0];
+    loop {
+        compiled.compute_into(&mut workspace, &read_sensors(), &mut outputs)?;
+    }
+
+The compiled plan is a snapshot, later changes of the source system are not reflected.
+*/
+
+use crate::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    float::Float,
+    math_helpers::{
+        are_singletons, centroid_knots, is_piecewise_linear, linspace, piecewise_linear_centroid,
+        singleton_centroid,
+    },
+    membership::MembershipKind,
+    operators::{Aggregation, Complement, Implication, SNorm, TNorm},
+    rule::{Connective, Expression, Rule},
+    variable::{LinguisticVariable, Range},
+};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+
+// Antecedent expression with variables and terms resolved to indices
+#[derive(Debug, Clone)]
+enum CompiledExpression {
+    Is { input: usize, term: usize },
+    Not(Box<CompiledExpression>),
+    And(Vec<CompiledExpression>),
+    Or(Vec<CompiledExpression>),
+}
+
+#[derive(Debug, Clone)]
+enum CompiledAntecedent {
+    // (term index, negated) per input variable, None = wildcard
+    Clauses {
+        clauses: Vec<Option<(usize, bool)>>,
+        connective: Connective,
+    },
+    Expression(CompiledExpression),
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule<F: Float> {
+    antecedent: CompiledAntecedent,
+    weight: F,
+    // term index per output variable, None = output not affected by the rule
+    consequent: Vec<Option<usize>>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledOutput<F: Float> {
+    range: Range<F>,
+    terms: Vec<MembershipKind<F>>,
+    defuzzifier: Defuzzifier<F>,
+    // Mamdani only: discretized output axis, every term sampled on it and the term peaks
+    xs: Vec<F>,
+    curves: Vec<Vec<F>>,
+    peaks: Vec<Option<F>>,
+    // Mamdani centroid of piecewise linear terms: knots of the exact computation
+    knots: Option<Vec<F>>,
+    // Mamdani centroid of singleton terms: a weighted average of the fired points
+    singletons: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompiledSystem<F: Float = f64> {
+    fis_type: FisType,
+    inputs: Vec<Vec<MembershipKind<F>>>,
+    outputs: Vec<CompiledOutput<F>>,
+    rules: Vec<CompiledRule<F>>,
+    and_method: TNorm<F>,
+    or_method: SNorm<F>,
+    implication: Implication,
+    aggregation: Aggregation,
+    complement: Complement<F>,
+}
+
+// Scratch buffers reused across evaluations of one CompiledSystem
+#[derive(Debug, Clone)]
+pub struct Workspace<F: Float = f64> {
+    fire: Vec<F>,
+    agg: Vec<F>,
+    // lines and cuts of the exact centroid, one per rule
+    lines: Vec<[F; 3]>,
+    cuts: Vec<F>,
+}
+
+pub fn compile<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    fis_type: FisType,
+) -> Result<CompiledSystem<F>, FisError> {
+    let rules = fis
+        .rules
+        .iter()
+        .map(|rule| compile_rule(fis, rule))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let outputs = fis
+        .outputs
+        .iter()
+        .map(|var| compile_output(fis, var, fis_type))
+        .collect::<Vec<_>>();
+
+    // Consequents must suit the inference type, as checked by compute on every call
+    for (rule, source) in rules.iter().zip(&fis.rules) {
+        for (out_idx, term) in rule.consequent.iter().enumerate() {
+            let Some(term) = term else { continue };
+            let kind = &outputs[out_idx].terms[*term];
+            let name = &source.consequent[out_idx];
+            match fis_type {
+                FisType::Sugeno if kind.evaluate(&vec![F::ZERO; fis.inputs.len()]).is_none() => {
+                    return Err(FisError::InvalidSugenoTerm(name.clone()));
+                }
+                FisType::Tsukamoto if kind.inverse(F::ZERO).is_none() => {
+                    return Err(FisError::NonMonotonicTerm(name.clone()));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(CompiledSystem {
+        fis_type,
+        inputs: fis
+            .inputs
+            .iter()
+            .map(|var| var.terms.iter().map(|t| t.kind.clone()).collect())
+            .collect(),
+        outputs,
+        rules,
+        and_method: fis.and_method,
+        or_method: fis.or_method,
+        implication: fis.implication,
+        aggregation: fis.aggregation,
+        complement: fis.complement,
+    })
+}
+
+fn term_index<F: Float>(var: &LinguisticVariable<F>, name: &str) -> Result<usize, FisError> {
+    var.terms
+        .iter()
+        .position(|t| t.name == name)
+        .ok_or_else(|| FisError::TermNotFound(name.to_string()))
+}
+
+fn compile_rule<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    rule: &Rule,
+) -> Result<CompiledRule<F>, FisError> {
+    if !(0.0..=1.0).contains(&rule.weight) {
+        return Err(FisError::InvalidRuleWeight(rule.weight));
+    }
+
+    let antecedent = match &rule.expression {
+        Some(expression) => CompiledAntecedent::Expression(compile_expression(fis, expression)?),
+        None => {
+            if rule.antecedent.len() > fis.inputs.len() {
+                return Err(FisError::InputLen {
+                    expected: fis.inputs.len(),
+                    got: rule.antecedent.len(),
+                });
+            }
+            let clauses = rule
+                .antecedent
+                .iter()
+                .enumerate()
+                .map(|(i, term)| match term {
+                    Some(name) => Ok(Some((
+                        term_index(&fis.inputs[i], name)?,
+                        rule.is_negated(i),
+                    ))),
+                    None => Ok(None),
+                })
+                .collect::<Result<Vec<_>, FisError>>()?;
+            CompiledAntecedent::Clauses {
+                clauses,
+                connective: rule.connective,
+            }
+        }
+    };
+
+    // consequents beyond the declared outputs are never read, as in compute
+    let consequent = fis
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(out_idx, var)| match rule.consequent.get(out_idx) {
+            Some(name) if !name.is_empty() => term_index(var, name).map(Some),
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CompiledRule {
+        antecedent,
+        weight: F::from_f64(rule.weight),
+        consequent,
+    })
+}
+
+fn compile_expression<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    expression: &Expression,
+) -> Result<CompiledExpression, FisError> {
+    Ok(match expression {
+        Expression::Is { variable, term } => {
+            let input = fis
+                .inputs
+                .iter()
+                .position(|v| &v.name == variable)
+                .ok_or_else(|| FisError::VariableNotFound(variable.clone()))?;
+            CompiledExpression::Is {
+                input,
+                term: term_index(&fis.inputs[input], term)?,
+            }
+        }
+        Expression::Not(inner) => {
+            CompiledExpression::Not(Box::new(compile_expression(fis, inner)?))
+        }
+        Expression::And(operands) => CompiledExpression::And(
+            operands
+                .iter()
+                .map(|e| compile_expression(fis, e))
+                .collect::<Result<_, _>>()?,
+        ),
+        Expression::Or(operands) => CompiledExpression::Or(
+            operands
+                .iter()
+                .map(|e| compile_expression(fis, e))
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+fn compile_output<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    var: &LinguisticVariable<F>,
+    fis_type: FisType,
+) -> CompiledOutput<F> {
+    let defuzzifier = var
+        .defuzzifier
+        .clone()
+        .unwrap_or_else(|| fis.defuzzifier.clone());
+    let (xs, curves, peaks) = if fis_type == FisType::Mamdani {
+        let xs = linspace(var.range.min, var.range.max, fis.resolution);
+        let curves = var
+            .terms
+            .iter()
+            .map(|t| xs.iter().map(|x| t.degree(*x)).collect())
+            .collect();
+        let peaks = var.terms.iter().map(|t| t.kind.peak()).collect();
+        (xs, curves, peaks)
+    } else {
+        (Vec::new(), Vec::new(), Vec::new())
+    };
+    let kinds = var.terms.iter().map(|t| &t.kind);
+    let singletons = fis_type == FisType::Mamdani
+        && matches!(defuzzifier, Defuzzifier::Centroid)
+        && are_singletons(kinds.clone());
+    let knots = match defuzzifier {
+        Defuzzifier::Centroid
+            if fis_type == FisType::Mamdani
+                && is_piecewise_linear(kinds.clone(), fis.aggregation) =>
+        {
+            Some(centroid_knots(var.range, kinds))
+        }
+        _ => None,
+    };
+
+    CompiledOutput {
+        range: var.range,
+        terms: var.terms.iter().map(|t| t.kind.clone()).collect(),
+        defuzzifier,
+        xs,
+        curves,
+        peaks,
+        knots,
+        singletons,
+    }
+}
+
+impl<F: Float> CompiledSystem<F> {
+    pub fn fis_type(&self) -> FisType {
+        self.fis_type
+    }
+
+    // Buffers sized for this system, to be passed to every compute_into call
+    pub fn workspace(&self) -> Workspace<F> {
+        Workspace {
+            fire: vec![F::ZERO; self.rules.len()],
+            agg: vec![F::ZERO; self.outputs.iter().map(|o| o.xs.len()).max().unwrap_or(0)],
+            lines: vec![[F::ZERO; 3]; self.rules.len()],
+            cuts: vec![F::ZERO; self.rules.len()],
+        }
+    }
+
+    // Allocating convenience wrapper around compute_into
+    pub fn compute(&self, crisp_inputs: &[F]) -> Result<Vec<F>, FisError> {
+        let mut workspace = self.workspace();
+        let mut outputs = vec![F::ZERO; self.outputs.len()];
+        self.compute_into(&mut workspace, crisp_inputs, &mut outputs)?;
+        Ok(outputs)
+    }
+
+    /*
+    Evaluate many samples given as input rows. Outputs are returned row-major,
+    `outputs.len()` values per row:
+
+        rows:    [[t0, h0], [t1, h1], ...]
+        outputs: [speed0, speed1, ...]
+
+    Buffers are reused across rows; with the `rayon` feature rows are split
+    across threads, each with its own workspace.
+    */
+    pub fn compute_batch<R: AsRef<[F]> + Sync>(&self, rows: &[R]) -> Result<Vec<F>, FisError> {
+        if let Some(row) = rows.iter().find(|r| r.as_ref().len() != self.inputs.len()) {
+            return Err(FisError::InputLen {
+                expected: self.inputs.len(),
+                got: row.as_ref().len(),
+            });
+        }
+        self.compute_rows(rows.len(), |r, row| row.copy_from_slice(rows[r].as_ref()))
+    }
+
+    // Column-major variant of compute_batch: one slice of samples per input variable,
+    // one column of results per output variable
+    pub fn compute_batch_columns(&self, columns: &[&[F]]) -> Result<Vec<Vec<F>>, FisError> {
+        if columns.len() != self.inputs.len() {
+            return Err(FisError::InputLen {
+                expected: self.inputs.len(),
+                got: columns.len(),
+            });
+        }
+        let n_rows = columns.first().map_or(0, |c| c.len());
+        if let Some(column) = columns.iter().find(|c| c.len() != n_rows) {
+            return Err(FisError::InputLen {
+                expected: n_rows,
+                got: column.len(),
+            });
+        }
+
+        let rows = self.compute_rows(n_rows, |r, row| {
+            for (x, column) in row.iter_mut().zip(columns) {
+                *x = column[r];
+            }
+        })?;
+
+        let n_out = self.outputs.len();
+        Ok((0..n_out)
+            .map(|j| rows.iter().skip(j).step_by(n_out).copied().collect())
+            .collect())
+    }
+
+    // Row-major outputs for `n_rows` samples, `gather` fills the input buffer of a row
+    fn compute_rows(
+        &self,
+        n_rows: usize,
+        gather: impl Fn(usize, &mut [F]) + Sync,
+    ) -> Result<Vec<F>, FisError> {
+        let n_out = self.outputs.len();
+        let mut outputs = vec![F::ZERO; n_rows * n_out];
+        if n_out == 0 {
+            return Ok(outputs);
+        }
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            outputs
+                .par_chunks_mut(n_out)
+                .enumerate()
+                .try_for_each_init(
+                    || (self.workspace(), vec![F::ZERO; self.inputs.len()]),
+                    |(workspace, row), (r, out)| {
+                        gather(r, row);
+                        self.compute_into(workspace, row, out)
+                    },
+                )?;
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut workspace = self.workspace();
+            let mut row = vec![F::ZERO; self.inputs.len()];
+            for (r, out) in outputs.chunks_mut(n_out).enumerate() {
+                gather(r, &mut row);
+                self.compute_into(&mut workspace, &row, out)?;
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    // Evaluate the plan, writing one crisp value per output variable into `outputs`
+    pub fn compute_into(
+        &self,
+        workspace: &mut Workspace<F>,
+        crisp_inputs: &[F],
+        outputs: &mut [F],
+    ) -> Result<(), FisError> {
+        if crisp_inputs.len() != self.inputs.len() {
+            return Err(FisError::InputLen {
+                expected: self.inputs.len(),
+                got: crisp_inputs.len(),
+            });
+        }
+        if outputs.len() != self.outputs.len() {
+            return Err(FisError::OutputMismatch);
+        }
+        // a workspace of another plan is replaced once instead of being rejected
+        let agg_len = self.outputs.iter().map(|o| o.xs.len()).max().unwrap_or(0);
+        if workspace.fire.len() != self.rules.len() || workspace.agg.len() < agg_len {
+            *workspace = self.workspace();
+        }
+
+        for (fire, rule) in workspace.fire.iter_mut().zip(&self.rules) {
+            *fire = self.firing_strength(rule, crisp_inputs);
+        }
+
+        for (out_idx, (out, crisp)) in self.outputs.iter().zip(outputs.iter_mut()).enumerate() {
+            *crisp = match self.fis_type {
+                FisType::Mamdani => self.defuzzify_mamdani(workspace, out_idx, out),
+                FisType::Sugeno | FisType::Tsukamoto => {
+                    self.weighted_average(&workspace.fire, crisp_inputs, out_idx, out)
+                }
+            };
+        }
+
+        Ok(())
+    }
+
+    fn firing_strength(&self, rule: &CompiledRule<F>, crisp_inputs: &[F]) -> F {
+        let fire = match &rule.antecedent {
+            CompiledAntecedent::Expression(expression) => self.evaluate(expression, crisp_inputs),
+            CompiledAntecedent::Clauses {
+                clauses,
+                connective,
+            } => {
+                // wildcards are skipped, so they are neutral under AND and OR
+                let degrees = clauses.iter().enumerate().filter_map(|(i, clause)| {
+                    let (term, negated) = clause.as_ref()?;
+                    let mu = self.inputs[i][*term].degree(crisp_inputs[i]);
+                    Some(if *negated {
+                        self.complement.apply(mu)
+                    } else {
+                        mu
+                    })
+                });
+                match connective {
+                    Connective::And => degrees.fold(F::ONE, |a, d| self.and_method.apply(a, d)),
+                    Connective::Or => degrees.fold(F::ZERO, |a, d| self.or_method.apply(a, d)),
+                }
+            }
+        };
+        fire * rule.weight
+    }
+
+    fn evaluate(&self, expression: &CompiledExpression, crisp_inputs: &[F]) -> F {
+        match expression {
+            CompiledExpression::Is { input, term } => {
+                self.inputs[*input][*term].degree(crisp_inputs[*input])
+            }
+            CompiledExpression::Not(inner) => {
+                self.complement.apply(self.evaluate(inner, crisp_inputs))
+            }
+            CompiledExpression::And(operands) => operands.iter().fold(F::ONE, |a, e| {
+                self.and_method.apply(a, self.evaluate(e, crisp_inputs))
+            }),
+            CompiledExpression::Or(operands) => operands.iter().fold(F::ZERO, |a, e| {
+                self.or_method.apply(a, self.evaluate(e, crisp_inputs))
+            }),
+        }
+    }
+
+    fn defuzzify_mamdani(
+        &self,
+        workspace: &mut Workspace<F>,
+        out_idx: usize,
+        out: &CompiledOutput<F>,
+    ) -> F {
+        let agg = &mut workspace.agg[..out.xs.len()];
+        agg.fill(F::ZERO);
+        let mut peak_num = F::ZERO;
+        let mut peak_den = F::ZERO;
+
+        let implied = self
+            .rules
+            .iter()
+            .zip(&workspace.fire)
+            .filter_map(|(rule, fire)| Some((*fire, &out.terms[rule.consequent[out_idx]?])));
+        if out.singletons {
+            if let Some(centroid) = singleton_centroid(implied, self.implication, self.aggregation)
+            {
+                return centroid;
+            }
+        } else if let Some(knots) = &out.knots {
+            if let Some(centroid) = piecewise_linear_centroid(
+                knots.iter().copied(),
+                implied,
+                self.implication,
+                self.aggregation,
+                &mut workspace.lines,
+                &mut workspace.cuts,
+            ) {
+                return centroid;
+            }
+        }
+
+        for (rule, fire) in self.rules.iter().zip(&workspace.fire) {
+            let Some(term) = rule.consequent[out_idx] else {
+                continue;
+            };
+            if let Some(peak) = out.peaks[term] {
+                peak_num += *fire * peak;
+                peak_den += *fire;
+            }
+            for (a, mu) in agg.iter_mut().zip(&out.curves[term]) {
+                *a = self
+                    .aggregation
+                    .apply(*a, self.implication.apply(*fire, *mu));
+            }
+        }
+
+        self.aggregation.normalize(agg);
+
+        match out.defuzzifier {
+            Defuzzifier::WeightedAverage if peak_den > F::ZERO => peak_num / peak_den,
+            ref other => other.defuzzify(&out.xs, agg),
+        }
+    }
+
+    fn weighted_average(
+        &self,
+        fire: &[F],
+        crisp_inputs: &[F],
+        out_idx: usize,
+        out: &CompiledOutput<F>,
+    ) -> F {
+        let mut num = F::ZERO;
+        let mut den = F::ZERO;
+
+        for (rule, fire) in self.rules.iter().zip(fire) {
+            let Some(term) = rule.consequent[out_idx] else {
+                continue;
+            };
+            let kind = &out.terms[term];
+            // consequent kinds were checked by compile
+            let z = match self.fis_type {
+                FisType::Tsukamoto => kind.inverse(*fire),
+                _ => kind.evaluate(crisp_inputs),
+            }
+            .unwrap_or_default();
+            num += *fire * z;
+            den += *fire;
+        }
+
+        // No rule fired: fall back to the middle of the output range
+        if den == F::ZERO {
+            (out.range.min + out.range.max) / F::TWO
+        } else {
+            num / den
+        }
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::fmt;
+
+use crate::float::Float;
+use crate::math_helpers::{
+    bisector, centroid, largest_of_maximum, mean_of_maximum, smallest_of_maximum,
+};
+
+// User supplied defuzzification: receives the discretized output axis and
+// the aggregated membership degree sampled at every point of it
+pub type DefuzzifyFn<F = f64> = dyn Fn(&[F], &[F]) -> F + Send + Sync;
+
+// Shared closure with an allocator, a static function without one
+#[cfg(feature = "alloc")]
+pub type CustomDefuzzifier<F> = Arc<DefuzzifyFn<F>>;
+#[cfg(not(feature = "alloc"))]
+pub type CustomDefuzzifier<F> = &'static DefuzzifyFn<F>;
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Defuzzifier<F: Float = f64> {
+    // center of gravity of the aggregated set
+    #[default]
+    Centroid,
+    // point splitting the aggregated set area into two equal halves
+    Bisector,
+    // mean, smallest and largest point where the aggregated set reaches its maximum
+    MeanOfMaximum,
+    SmallestOfMaximum,
+    LargestOfMaximum,
+    // consequent term peaks weighted by rule firing strengths
+    // (on a bare sampled set, as in `defuzzify`, it equals the centroid)
+    WeightedAverage,
+    // closures cannot be serialized, systems using one fail to serialize
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(CustomDefuzzifier<F>),
+}
+
+impl<F: Float> Defuzzifier<F> {
+    #[cfg(feature = "alloc")]
+    pub fn custom(f: impl Fn(&[F], &[F]) -> F + Send + Sync + 'static) -> Self {
+        Defuzzifier::Custom(Arc::new(f))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    pub const fn custom(f: &'static DefuzzifyFn<F>) -> Self {
+        Defuzzifier::Custom(f)
+    }
+
+    pub fn defuzzify(&self, xs: &[F], mus: &[F]) -> F {
+        match self {
+            Defuzzifier::Centroid | Defuzzifier::WeightedAverage => centroid(xs, mus),
+            Defuzzifier::Bisector => bisector(xs, mus),
+            Defuzzifier::MeanOfMaximum => mean_of_maximum(xs, mus),
+            Defuzzifier::SmallestOfMaximum => smallest_of_maximum(xs, mus),
+            Defuzzifier::LargestOfMaximum => largest_of_maximum(xs, mus),
+            Defuzzifier::Custom(f) => f(xs, mus),
+        }
+    }
+}
+
+impl<F: Float> fmt::Debug for Defuzzifier<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Defuzzifier::Centroid => write!(f, "Centroid"),
+            Defuzzifier::Bisector => write!(f, "Bisector"),
+            Defuzzifier::MeanOfMaximum => write!(f, "MeanOfMaximum"),
+            Defuzzifier::SmallestOfMaximum => write!(f, "SmallestOfMaximum"),
+            Defuzzifier::LargestOfMaximum => write!(f, "LargestOfMaximum"),
+            Defuzzifier::WeightedAverage => write!(f, "WeightedAverage"),
+            Defuzzifier::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
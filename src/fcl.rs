@@ -0,0 +1,582 @@
+/*
+IEC 61131-7 Fuzzy Control Language (FCL).
+
+FUNCTION_BLOCK tipper
+VAR_INPUT
+    service : REAL;
+END_VAR
+VAR_OUTPUT
+    tip : REAL;
+END_VAR
+FUZZIFY service
+    TERM poor := (0, 1) (4, 0);
+    TERM good := (1, 0) (4, 1) (6, 1) (9, 0);
+    RANGE := (0 .. 10);
+END_FUZZIFY
+DEFUZZIFY tip
+    TERM cheap := (0, 0) (5, 1) (10, 0);
+    METHOD : COG;
+    DEFAULT := 0;
+END_DEFUZZIFY
+RULEBLOCK No1
+    AND : MIN;
+    ACT : MIN;
+    ACCU : MAX;
+    RULE 1 : IF service IS poor THEN tip IS cheap;
+END_RULEBLOCK
+END_FUNCTION_BLOCK
+
+Point lists are mapped onto Triangle / Trapezoid shapes, or onto LeftShoulder /
+RightShoulder when the list starts or ends fully true, any other list onto a
+Polygon, a single value onto `MembershipKind::Singleton`. A DEFUZZIFY block
+using METHOD : COGS makes the system a zero-order Sugeno one, its singleton
+outputs becoming `MembershipKind::Constant` consequents.
+
+Keyword mapping:
+  AND : MIN | PROD | BDIF        -> TNorm::Min | Product | Lukasiewicz (with the dual OR)
+  OR  : MAX | ASUM | BSUM        -> SNorm::Max | ProbabilisticSum | BoundedSum
+  ACT : MIN | PROD               -> Implication::Min | Product
+  ACCU: MAX | BSUM | NSUM | ASUM -> Aggregation::Max | BoundedSum | NormalizedSum | ProbabilisticSum
+  METHOD : COG | COA | MOM | LM | RM | COGS
+         -> Centroid | Bisector | MeanOfMaximum | SmallestOfMaximum | LargestOfMaximum | weighted average
+*/
+use crate::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    float::Float,
+    membership::MembershipKind,
+    operators::{Aggregation, Implication, SNorm, TNorm},
+    parser::{format_rule, parse_rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+fn format_error(line: usize, message: impl Into<String>) -> FisError {
+    FisError::FclFormat {
+        line,
+        message: message.into(),
+    }
+}
+
+fn unsupported(what: impl Into<String>) -> FisError {
+    FisError::UnsupportedFclConstruct(what.into())
+}
+
+// Drop (* block *) and // line comments, keeping line numbers intact
+fn strip_comments(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_block = false;
+    for line in text.lines() {
+        let mut rest = line;
+        loop {
+            if in_block {
+                match rest.find("*)") {
+                    Some(end) => {
+                        rest = &rest[end + 2..];
+                        in_block = false;
+                    }
+                    None => break,
+                }
+            } else {
+                let block = rest.find("(*");
+                let line_comment = rest.find("//");
+                match (block, line_comment) {
+                    (Some(b), Some(l)) if l < b => {
+                        out.push_str(&rest[..l]);
+                        break;
+                    }
+                    (Some(b), _) => {
+                        out.push_str(&rest[..b]);
+                        rest = &rest[b + 2..];
+                        in_block = true;
+                    }
+                    (None, Some(l)) => {
+                        out.push_str(&rest[..l]);
+                        break;
+                    }
+                    (None, None) => {
+                        out.push_str(rest);
+                        break;
+                    }
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn parse_number(line: usize, text: &str) -> Result<f64, FisError> {
+    text.trim()
+        .parse::<f64>()
+        .map_err(|_| format_error(line, format!("invalid number '{}'", text.trim())))
+}
+
+// (0, 1) (4, 0)  or a single singleton value
+fn parse_points(line: usize, text: &str) -> Result<Vec<(f64, f64)>, FisError> {
+    let text = text.trim();
+    if !text.starts_with('(') {
+        return Ok(vec![(parse_number(line, text)?, 1.0)]);
+    }
+    let mut points = Vec::new();
+    for chunk in text.split(')') {
+        let chunk = chunk.trim();
+        if chunk.is_empty() {
+            continue;
+        }
+        let inner = chunk
+            .strip_prefix('(')
+            .ok_or_else(|| format_error(line, format!("expected '(x, mu)', got '{}'", chunk)))?;
+        let (x, mu) = inner
+            .split_once(',')
+            .ok_or_else(|| format_error(line, format!("expected '(x, mu)', got '({})'", inner)))?;
+        points.push((parse_number(line, x)?, parse_number(line, mu)?));
+    }
+    Ok(points)
+}
+
+fn points_to_membership<F: Float>(points: &[(f64, f64)]) -> Option<MembershipKind<F>> {
+    let mus: Vec<f64> = points.iter().map(|(_, mu)| *mu).collect();
+    let xs: Vec<F> = points.iter().map(|(x, _)| F::from_f64(*x)).collect();
+    match mus.as_slice() {
+        [m] if *m == 1.0 => Some(MembershipKind::Singleton { value: xs[0] }),
+        [0.0, 1.0, 0.0] => Some(MembershipKind::Triangle {
+            a: xs[0],
+            b: xs[1],
+            c: xs[2],
+        }),
+        [0.0, 1.0, 1.0, 0.0] => Some(MembershipKind::Trapezoid {
+            a: xs[0],
+            b: xs[1],
+            c: xs[2],
+            d: xs[3],
+        }),
+        // left shoulder: fully true up to the falling edge
+        [1.0, 0.0] | [1.0, 1.0, 0.0] => Some(MembershipKind::LeftShoulder {
+            a: xs[xs.len() - 2],
+            b: xs[xs.len() - 1],
+        }),
+        // right shoulder: fully true after the rising edge
+        [0.0, 1.0] | [0.0, 1.0, 1.0] => Some(MembershipKind::RightShoulder { a: xs[0], b: xs[1] }),
+        [] => None,
+        // any other shape, e.g. a curve drawn by an expert
+        _ => Some(MembershipKind::Polygon {
+            points: xs
+                .into_iter()
+                .zip(mus.iter().map(|mu| F::from_f64(*mu)))
+                .collect(),
+        }),
+    }
+}
+
+fn membership_to_points<F: Float>(kind: &MembershipKind<F>) -> Result<String, FisError> {
+    let points: Vec<(F, f64)> = match *kind {
+        MembershipKind::Singleton { value } | MembershipKind::Constant { value } => {
+            return Ok(value.to_string())
+        }
+        MembershipKind::Triangle { a, b, c } => vec![(a, 0.0), (b, 1.0), (c, 0.0)],
+        MembershipKind::Trapezoid { a, b, c, d } => vec![(a, 0.0), (b, 1.0), (c, 1.0), (d, 0.0)]
+            .into_iter()
+            .filter(|(x, _)| x.to_f64().is_finite())
+            .collect(),
+        MembershipKind::LeftShoulder { a, b } => vec![(a, 1.0), (b, 0.0)],
+        MembershipKind::RightShoulder { a, b } => vec![(a, 0.0), (b, 1.0)],
+        MembershipKind::Polygon { ref points } => {
+            points.iter().map(|(x, mu)| (*x, mu.to_f64())).collect()
+        }
+        MembershipKind::Sampled { ref values, .. } => kind
+            .breakpoints()
+            .into_iter()
+            .flatten()
+            .zip(values.iter().map(|mu| mu.to_f64()))
+            .collect(),
+        _ => return Err(unsupported(format!("membership function {:?}", kind))),
+    };
+    let parts: Vec<String> = points
+        .iter()
+        .map(|(x, mu)| format!("({}, {})", x, mu))
+        .collect();
+    Ok(parts.join(" "))
+}
+
+#[derive(PartialEq)]
+enum Block {
+    None,
+    VarInput,
+    VarOutput,
+    Fuzzify(usize),
+    Defuzzify(usize),
+    Rules,
+}
+
+fn set_once<T: PartialEq + Copy>(
+    slot: &mut Option<T>,
+    value: T,
+    what: &str,
+) -> Result<(), FisError> {
+    match slot {
+        Some(existing) if *existing != value => Err(unsupported(format!(
+            "conflicting {} between rule blocks",
+            what
+        ))),
+        _ => {
+            *slot = Some(value);
+            Ok(())
+        }
+    }
+}
+
+// Build a system from FCL text, with the inference type implied by its DEFUZZIFY methods
+pub fn from_fcl_str<F: Float>(text: &str) -> Result<(FuzzyInferenceSystem<F>, FisType), FisError> {
+    let text = strip_comments(text);
+    let mut fis = FuzzyInferenceSystem::new("");
+    let mut fis_type = FisType::Mamdani;
+    let mut block = Block::None;
+    let mut ranges: Vec<(bool, usize, f64, f64)> = Vec::new(); // (is_output, idx, min, max)
+    let mut term_points: Vec<(bool, usize, f64)> = Vec::new(); // (is_output, idx, x)
+    let mut rule_lines: Vec<(usize, String)> = Vec::new();
+    let mut and_method = None;
+    let mut or_method = None;
+    let mut implication = None;
+    let mut aggregation = None;
+
+    for (i, raw) in text.lines().enumerate() {
+        let line = i + 1;
+        let stmt = raw.trim().trim_end_matches(';').trim();
+        if stmt.is_empty() {
+            continue;
+        }
+        let upper = stmt.to_ascii_uppercase();
+        let keyword = upper.split_whitespace().next().unwrap_or("");
+
+        match keyword {
+            "FUNCTION_BLOCK" => {
+                fis.name = stmt["FUNCTION_BLOCK".len()..].trim().to_string();
+                continue;
+            }
+            "END_FUNCTION_BLOCK" => continue,
+            "VAR_INPUT" => {
+                block = Block::VarInput;
+                continue;
+            }
+            "VAR_OUTPUT" => {
+                block = Block::VarOutput;
+                continue;
+            }
+            "END_VAR" | "END_FUZZIFY" | "END_DEFUZZIFY" | "END_RULEBLOCK" => {
+                block = Block::None;
+                continue;
+            }
+            "FUZZIFY" | "DEFUZZIFY" => {
+                let name = stmt[keyword.len()..].trim();
+                let (vars, is_output) = if keyword == "FUZZIFY" {
+                    (&fis.inputs, false)
+                } else {
+                    (&fis.outputs, true)
+                };
+                let idx = vars
+                    .iter()
+                    .position(|v| v.name == name)
+                    .ok_or_else(|| format_error(line, format!("undeclared variable '{}'", name)))?;
+                block = if is_output {
+                    Block::Defuzzify(idx)
+                } else {
+                    Block::Fuzzify(idx)
+                };
+                continue;
+            }
+            "RULEBLOCK" => {
+                block = Block::Rules;
+                continue;
+            }
+            _ => {}
+        }
+
+        match block {
+            Block::VarInput | Block::VarOutput => {
+                let (name, _ty) = stmt
+                    .split_once(':')
+                    .ok_or_else(|| format_error(line, "expected 'name : REAL'"))?;
+                let var = LinguisticVariable::new(
+                    name.trim(),
+                    Range {
+                        min: F::from_f64(f64::NAN),
+                        max: F::from_f64(f64::NAN),
+                    },
+                );
+                if block == Block::VarInput {
+                    fis.add_input(var);
+                } else {
+                    fis.add_output(var);
+                }
+            }
+            Block::Fuzzify(idx) | Block::Defuzzify(idx) => {
+                let is_output = matches!(block, Block::Defuzzify(_));
+                let var = if is_output {
+                    &mut fis.outputs[idx]
+                } else {
+                    &mut fis.inputs[idx]
+                };
+                match keyword {
+                    "TERM" => {
+                        let (name, points) = stmt["TERM".len()..]
+                            .split_once(":=")
+                            .ok_or_else(|| format_error(line, "expected 'TERM name := points'"))?;
+                        let points = parse_points(line, points)?;
+                        term_points.extend(points.iter().map(|(x, _)| (is_output, idx, *x)));
+                        let kind = points_to_membership(&points).ok_or_else(|| {
+                            unsupported(format!("term shape '{}'", stmt["TERM".len()..].trim()))
+                        })?;
+                        var.add_term(Term::new(name.trim(), kind))?;
+                    }
+                    "RANGE" => {
+                        let (_, value) = stmt.split_once(":=").ok_or_else(|| {
+                            format_error(line, "expected 'RANGE := (min .. max)'")
+                        })?;
+                        let value = value.trim().trim_start_matches('(').trim_end_matches(')');
+                        let (min, max) = value.split_once("..").ok_or_else(|| {
+                            format_error(line, "expected 'RANGE := (min .. max)'")
+                        })?;
+                        ranges.push((
+                            is_output,
+                            idx,
+                            parse_number(line, min)?,
+                            parse_number(line, max)?,
+                        ));
+                    }
+                    "METHOD" if is_output => {
+                        let (_, method) = stmt
+                            .split_once(':')
+                            .ok_or_else(|| format_error(line, "expected 'METHOD : name'"))?;
+                        var.defuzzifier = Some(match method.trim().to_ascii_uppercase().as_str() {
+                            "COG" => Defuzzifier::Centroid,
+                            "COA" => Defuzzifier::Bisector,
+                            "MOM" => Defuzzifier::MeanOfMaximum,
+                            "LM" => Defuzzifier::SmallestOfMaximum,
+                            "RM" => Defuzzifier::LargestOfMaximum,
+                            "COGS" => {
+                                fis_type = FisType::Sugeno;
+                                Defuzzifier::WeightedAverage
+                            }
+                            other => return Err(unsupported(format!("METHOD {}", other))),
+                        });
+                    }
+                    // no default value support, outputs fall back to the middle of their range
+                    "DEFAULT" if is_output => {}
+                    _ => return Err(format_error(line, format!("unexpected '{}'", stmt))),
+                }
+            }
+            Block::Rules => {
+                if keyword == "RULE" {
+                    let (_, rule) = stmt
+                        .split_once(':')
+                        .ok_or_else(|| format_error(line, "expected 'RULE n : IF ...'"))?;
+                    rule_lines.push((line, rule.trim().to_string()));
+                    continue;
+                }
+                let (key, value) = stmt
+                    .split_once(':')
+                    .ok_or_else(|| format_error(line, format!("unexpected '{}'", stmt)))?;
+                let value = value.trim().to_ascii_uppercase();
+                match (key.trim().to_ascii_uppercase().as_str(), value.as_str()) {
+                    ("AND", "MIN") => {
+                        set_once(&mut and_method, TNorm::Min, "AND")?;
+                        set_once(&mut or_method, SNorm::Max, "OR")?;
+                    }
+                    ("AND", "PROD") => {
+                        set_once(&mut and_method, TNorm::Product, "AND")?;
+                        set_once(&mut or_method, SNorm::ProbabilisticSum, "OR")?;
+                    }
+                    ("AND", "BDIF") => {
+                        set_once(&mut and_method, TNorm::Lukasiewicz, "AND")?;
+                        set_once(&mut or_method, SNorm::BoundedSum, "OR")?;
+                    }
+                    ("OR", "MAX") => {
+                        set_once(&mut or_method, SNorm::Max, "OR")?;
+                        set_once(&mut and_method, TNorm::Min, "AND")?;
+                    }
+                    ("OR", "ASUM") => {
+                        set_once(&mut or_method, SNorm::ProbabilisticSum, "OR")?;
+                        set_once(&mut and_method, TNorm::Product, "AND")?;
+                    }
+                    ("OR", "BSUM") => {
+                        set_once(&mut or_method, SNorm::BoundedSum, "OR")?;
+                        set_once(&mut and_method, TNorm::Lukasiewicz, "AND")?;
+                    }
+                    ("ACT", "MIN") => set_once(&mut implication, Implication::Min, "ACT")?,
+                    ("ACT", "PROD") => set_once(&mut implication, Implication::Product, "ACT")?,
+                    ("ACCU", "MAX") => set_once(&mut aggregation, Aggregation::Max, "ACCU")?,
+                    ("ACCU", "BSUM") => {
+                        set_once(&mut aggregation, Aggregation::BoundedSum, "ACCU")?
+                    }
+                    ("ACCU", "NSUM") => {
+                        set_once(&mut aggregation, Aggregation::NormalizedSum, "ACCU")?
+                    }
+                    ("ACCU", "ASUM") => {
+                        set_once(&mut aggregation, Aggregation::ProbabilisticSum, "ACCU")?
+                    }
+                    (key, value) => return Err(unsupported(format!("{} : {}", key, value))),
+                }
+            }
+            Block::None => return Err(format_error(line, format!("unexpected '{}'", stmt))),
+        }
+    }
+
+    // Universe of discourse: explicit RANGE, otherwise the span of the finite term points
+    for (is_output, vars) in [(false, &mut fis.inputs), (true, &mut fis.outputs)] {
+        for (idx, var) in vars.iter_mut().enumerate() {
+            if let Some((_, _, min, max)) = ranges
+                .iter()
+                .find(|(o, i, _, _)| *o == is_output && *i == idx)
+            {
+                var.range = Range {
+                    min: F::from_f64(*min),
+                    max: F::from_f64(*max),
+                };
+                continue;
+            }
+            let xs: Vec<f64> = term_points
+                .iter()
+                .filter(|(o, i, _)| *o == is_output && *i == idx)
+                .map(|(_, _, x)| *x)
+                .collect();
+            if xs.is_empty() {
+                return Err(unsupported(format!(
+                    "variable '{}' without range",
+                    var.name
+                )));
+            }
+            var.range = Range {
+                min: F::from_f64(xs.iter().copied().fold(f64::INFINITY, f64::min)),
+                max: F::from_f64(xs.iter().copied().fold(f64::NEG_INFINITY, f64::max)),
+            };
+        }
+    }
+
+    fis.and_method = and_method.unwrap_or_default();
+    fis.or_method = or_method.unwrap_or_default();
+    fis.implication = implication.unwrap_or_default();
+    fis.aggregation = aggregation.unwrap_or_default();
+
+    // COGS weights the singleton positions, i.e. zero-order Sugeno constants
+    if fis_type == FisType::Sugeno {
+        for term in fis.outputs.iter_mut().flat_map(|var| var.terms.iter_mut()) {
+            if let MembershipKind::Singleton { value } = term.kind {
+                term.kind = MembershipKind::Constant { value };
+            }
+        }
+    }
+
+    for (line, text) in rule_lines {
+        let rule = parse_rule(&text, &fis.inputs, &fis.outputs)
+            .map_err(|e| format_error(line, e.to_string()))?;
+        fis.rules.push(rule);
+    }
+
+    Ok((fis, fis_type))
+}
+
+// Serialize a system as FCL text for the given inference type
+pub fn to_fcl_string<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    fis_type: FisType,
+) -> Result<String, FisError> {
+    if fis_type == FisType::Tsukamoto {
+        return Err(unsupported("Tsukamoto inference"));
+    }
+
+    let mut out = String::new();
+    out.push_str(&format!("FUNCTION_BLOCK {}\n\n", fis.name));
+
+    out.push_str("VAR_INPUT\n");
+    for var in &fis.inputs {
+        out.push_str(&format!("    {} : REAL;\n", var.name));
+    }
+    out.push_str("END_VAR\n\nVAR_OUTPUT\n");
+    for var in &fis.outputs {
+        out.push_str(&format!("    {} : REAL;\n", var.name));
+    }
+    out.push_str("END_VAR\n");
+
+    for var in &fis.inputs {
+        out.push_str(&format!("\nFUZZIFY {}\n", var.name));
+        for term in &var.terms {
+            out.push_str(&format!(
+                "    TERM {} := {};\n",
+                term.name,
+                membership_to_points(&term.kind)?
+            ));
+        }
+        out.push_str(&format!(
+            "    RANGE := ({} .. {});\n",
+            var.range.min, var.range.max
+        ));
+        out.push_str("END_FUZZIFY\n");
+    }
+
+    for var in &fis.outputs {
+        out.push_str(&format!("\nDEFUZZIFY {}\n", var.name));
+        for term in &var.terms {
+            out.push_str(&format!(
+                "    TERM {} := {};\n",
+                term.name,
+                membership_to_points(&term.kind)?
+            ));
+        }
+        let method = if fis_type == FisType::Sugeno {
+            "COGS"
+        } else {
+            match var.defuzzifier.as_ref().unwrap_or(&fis.defuzzifier) {
+                Defuzzifier::Centroid => "COG",
+                Defuzzifier::Bisector => "COA",
+                Defuzzifier::MeanOfMaximum => "MOM",
+                Defuzzifier::SmallestOfMaximum => "LM",
+                Defuzzifier::LargestOfMaximum => "RM",
+                other => return Err(unsupported(format!("defuzzifier {:?}", other))),
+            }
+        };
+        out.push_str(&format!("    METHOD : {};\n", method));
+        out.push_str(&format!(
+            "    RANGE := ({} .. {});\n",
+            var.range.min, var.range.max
+        ));
+        out.push_str("END_DEFUZZIFY\n");
+    }
+
+    let and_method = match (fis.and_method, fis.or_method) {
+        (TNorm::Min, SNorm::Max) => "MIN",
+        (TNorm::Product, SNorm::ProbabilisticSum) => "PROD",
+        (TNorm::Lukasiewicz, SNorm::BoundedSum) => "BDIF",
+        (and, or) => return Err(unsupported(format!("AND {:?} with OR {:?}", and, or))),
+    };
+    let act = match fis.implication {
+        Implication::Min => "MIN",
+        Implication::Product => "PROD",
+        other => return Err(unsupported(format!("ACT {:?}", other))),
+    };
+    let accu = match fis.aggregation {
+        Aggregation::Max => "MAX",
+        Aggregation::BoundedSum => "BSUM",
+        Aggregation::NormalizedSum => "NSUM",
+        Aggregation::ProbabilisticSum => "ASUM",
+    };
+
+    out.push_str("\nRULEBLOCK No1\n");
+    out.push_str(&format!("    AND : {};\n", and_method));
+    out.push_str(&format!("    ACT : {};\n", act));
+    out.push_str(&format!("    ACCU : {};\n", accu));
+    for (i, rule) in fis.rules.iter().enumerate() {
+        let text = format_rule(rule, &fis.inputs, &fis.outputs)
+            .ok_or_else(|| unsupported(format!("rule {} without antecedent", i + 1)))?;
+        out.push_str(&format!("    RULE {} : {};\n", i + 1, text));
+    }
+    out.push_str("END_RULEBLOCK\n\nEND_FUNCTION_BLOCK\n");
+
+    Ok(out)
+}
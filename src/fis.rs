@@ -1,10 +1,25 @@
+#[cfg(feature = "alloc")]
 use crate::{
-    math_helpers::{centroid, linspace},
+    compiled::{compile, CompiledSystem},
+    defuzzifier::Defuzzifier,
+    fcl::{from_fcl_str, to_fcl_string},
+    fis_format::{from_fis_str, to_fis_string},
+    float::Float,
+    math_helpers::{
+        are_singletons, centroid_knots, is_piecewise_linear, linspace, piecewise_linear_centroid,
+        singleton_centroid,
+    },
+    operators::{Aggregation, Complement, Implication, SNorm, TNorm},
     output::OutputResult,
-    rule::{Connective, Rule},
+    parser::{parse_rule, RuleParseError},
+    rule::{Connective, Expression, Rule},
+    validation::{validate, Diagnostic},
     variable::LinguisticVariable,
 };
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec, vec::Vec};
 
+#[cfg(feature = "alloc")]
 #[derive(thiserror::Error, Debug)]
 pub enum FisError {
     #[error("input length mismatch: expected {expected}, got {got}")]
@@ -13,27 +28,92 @@ pub enum FisError {
     OutputMismatch,
     #[error("term not found: {0}")]
     TermNotFound(String),
+    // Never returned: every FisType is computed since Sugeno support, the
+    // variant is only kept so existing matches keep compiling
+    #[deprecated(note = "never returned, every FisType is supported")]
     #[error("undefined fuzzy inference system type")]
     UndefinedFuzzyInferenceSystemType,
     #[error("duplicate term: {0}")]
     DuplicateTerm(String),
+    #[error("invalid Sugeno consequent term: {0}")]
+    InvalidSugenoTerm(String),
+    #[error("non-monotonic Tsukamoto consequent term: {0}")]
+    NonMonotonicTerm(String),
+    #[error("rule weight must be within [0, 1], got {0}")]
+    InvalidRuleWeight(f64),
+    #[error("variable not found: {0}")]
+    VariableNotFound(String),
+    #[error(transparent)]
+    RuleParse(#[from] RuleParseError),
+    #[error(".fis format error at line {line}: {message}")]
+    FisFormat { line: usize, message: String },
+    #[error("unsupported .fis construct: {0}")]
+    UnsupportedFisConstruct(String),
+    #[error("FCL format error at line {line}: {message}")]
+    FclFormat { line: usize, message: String },
+    #[error("unsupported FCL construct: {0}")]
+    UnsupportedFclConstruct(String),
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FisType {
     Mamdani,
+    Sugeno,
+    Tsukamoto,
 }
 
-pub struct FuzzyInferenceSystem {
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FuzzyInferenceSystem<F: Float = f64> {
     pub name: String,
-    pub inputs: Vec<LinguisticVariable>,
-    pub outputs: Vec<LinguisticVariable>,
+    pub inputs: Vec<LinguisticVariable<F>>,
+    pub outputs: Vec<LinguisticVariable<F>>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub rules: Vec<Rule>,
     // discretization resolution for defuzzification
+    #[cfg_attr(feature = "serde", serde(default = "default_resolution"))]
     pub resolution: usize,
+    // Mamdani defuzzification method, unless overridden per output variable
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub defuzzifier: Defuzzifier<F>,
+    // operators applied by Connective::And and Connective::Or
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub and_method: TNorm<F>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub or_method: SNorm<F>,
+    // Mamdani shaping of consequents by firing strength and their combination
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub implication: Implication,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub aggregation: Aggregation,
+    // NOT operator for negated antecedent clauses
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub complement: Complement<F>,
 }
 
+#[cfg(all(feature = "alloc", feature = "serde"))]
+fn default_resolution() -> usize {
+    200
+}
+
+// Text imports produce f64 systems, `fis_format::from_fis_str` and
+// `fcl::from_fcl_str` load into other float types
+#[cfg(feature = "alloc")]
 impl FuzzyInferenceSystem {
+    // Load a system from MATLAB / Octave .fis text, with the inference type it declares
+    pub fn from_fis(text: &str) -> Result<(Self, FisType), FisError> {
+        from_fis_str(text)
+    }
+
+    // Load a system from IEC 61131-7 FCL text, with the inference type it implies
+    pub fn from_fcl(text: &str) -> Result<(Self, FisType), FisError> {
+        from_fcl_str(text)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> FuzzyInferenceSystem<F> {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
@@ -41,14 +121,20 @@ impl FuzzyInferenceSystem {
             outputs: Vec::new(),
             rules: Vec::new(),
             resolution: 200,
+            defuzzifier: Defuzzifier::Centroid,
+            and_method: TNorm::Min,
+            or_method: SNorm::Max,
+            implication: Implication::Min,
+            aggregation: Aggregation::Max,
+            complement: Complement::Standard,
         }
     }
 
-    pub fn add_input(&mut self, var: LinguisticVariable) {
+    pub fn add_input(&mut self, var: LinguisticVariable<F>) {
         self.inputs.push(var);
     }
 
-    pub fn add_output(&mut self, var: LinguisticVariable) {
+    pub fn add_output(&mut self, var: LinguisticVariable<F>) {
         self.outputs.push(var);
     }
 
@@ -56,8 +142,73 @@ impl FuzzyInferenceSystem {
         self.rules = rules;
     }
 
-    // Compute precise outputs using selected inference type and centroid defuzzification
-    pub fn compute(&self, fis_type: FisType, crisp_inputs: &[f64]) -> Result<Vec<f64>, FisError> {
+    // Export the system as MATLAB / Octave .fis text
+    pub fn to_fis(&self, fis_type: FisType) -> Result<String, FisError> {
+        to_fis_string(self, fis_type)
+    }
+
+    // Export the system as IEC 61131-7 FCL text
+    pub fn to_fcl(&self, fis_type: FisType) -> Result<String, FisError> {
+        to_fcl_string(self, fis_type)
+    }
+
+    // Structural problems of the model, all at once (empty when the system is well formed)
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        validate(self)
+    }
+
+    // Parse "IF ... THEN ..." rule text against this system's inputs and outputs
+    pub fn parse_rule(&self, text: &str) -> Result<Rule, RuleParseError> {
+        parse_rule(text, &self.inputs, &self.outputs)
+    }
+
+    pub fn add_rule_str(&mut self, text: &str) -> Result<(), FisError> {
+        let rule = self.parse_rule(text)?;
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    pub fn set_defuzzifier(&mut self, defuzzifier: Defuzzifier<F>) {
+        self.defuzzifier = defuzzifier;
+    }
+
+    pub fn set_and_method(&mut self, and_method: TNorm<F>) {
+        self.and_method = and_method;
+    }
+
+    pub fn set_or_method(&mut self, or_method: SNorm<F>) {
+        self.or_method = or_method;
+    }
+
+    pub fn set_implication(&mut self, implication: Implication) {
+        self.implication = implication;
+    }
+
+    pub fn set_aggregation(&mut self, aggregation: Aggregation) {
+        self.aggregation = aggregation;
+    }
+
+    pub fn set_complement(&mut self, complement: Complement<F>) {
+        self.complement = complement;
+    }
+
+    // Resolve terms and precompute output grids once, for repeated allocation-free evaluation
+    pub fn compile(&self, fis_type: FisType) -> Result<CompiledSystem<F>, FisError> {
+        compile(self, fis_type)
+    }
+
+    // Compile once and evaluate every input row, outputs row-major (see CompiledSystem::compute_batch)
+    pub fn compute_batch<R: AsRef<[F]> + Sync>(
+        &self,
+        fis_type: FisType,
+        rows: &[R],
+    ) -> Result<Vec<F>, FisError> {
+        self.compile(fis_type)?.compute_batch(rows)
+    }
+
+    // Compute precise outputs using selected inference type
+    // (configured defuzzifier for Mamdani, weighted average for Sugeno and Tsukamoto)
+    pub fn compute(&self, fis_type: FisType, crisp_inputs: &[F]) -> Result<Vec<F>, FisError> {
         if crisp_inputs.len() != self.inputs.len() {
             return Err(FisError::InputLen {
                 expected: self.inputs.len(),
@@ -65,86 +216,270 @@ impl FuzzyInferenceSystem {
             });
         }
 
-        if fis_type == FisType::Mamdani {
-            // For each output variable, aggregate membership by max over rule implications
-            let mut outputs_crisp = Vec::with_capacity(self.outputs.len());
-
-            for (out_idx, out_var) in self.outputs.iter().enumerate() {
-                // Initialize aggregated membership curve across discretized range
-                let xs = linspace(out_var.range.min, out_var.range.max, self.resolution);
-                let mut agg: Vec<f64> = vec![0.0; xs.len()];
-
-                for rule in &self.rules {
-                    // Rule firing strength from antecedents
-                    let mut degrees: Vec<f64> = Vec::with_capacity(self.inputs.len());
-                    for (i, ant_term_opt) in rule.antecedent.iter().enumerate() {
-                        let deg = match ant_term_opt {
-                            Some(term_name) => {
-                                let var = &self.inputs[i];
-                                let term = var
-                                    .term(term_name)
-                                    .ok_or_else(|| FisError::TermNotFound(term_name.clone()))?;
-                                term.degree(crisp_inputs[i])
-                            }
-                            None => 1.0, // wildcard
-                        };
-                        degrees.push(deg);
+        match fis_type {
+            FisType::Mamdani => self.compute_mamdani(crisp_inputs),
+            FisType::Sugeno => self.compute_sugeno(crisp_inputs),
+            FisType::Tsukamoto => self.compute_tsukamoto(crisp_inputs),
+        }
+    }
+
+    // Rule firing strength from antecedents, scaled by the rule weight
+    fn firing_strength(&self, rule: &Rule, crisp_inputs: &[F]) -> Result<F, FisError> {
+        if !(0.0..=1.0).contains(&rule.weight) {
+            return Err(FisError::InvalidRuleWeight(rule.weight));
+        }
+        let weight = F::from_f64(rule.weight);
+
+        if let Some(expression) = &rule.expression {
+            let fire = self.evaluate_expression(expression, crisp_inputs)?;
+            return Ok(fire * weight);
+        }
+
+        let mut degrees: Vec<F> = Vec::with_capacity(self.inputs.len());
+        for (i, ant_term_opt) in rule.antecedent.iter().enumerate() {
+            let deg = match ant_term_opt {
+                Some(term_name) => {
+                    let var = &self.inputs[i];
+                    let term = var
+                        .term(term_name)
+                        .ok_or_else(|| FisError::TermNotFound(term_name.clone()))?;
+                    let mu = term.degree(crisp_inputs[i]);
+                    if rule.is_negated(i) {
+                        self.complement.apply(mu)
+                    } else {
+                        mu
                     }
+                }
+                // wildcard: skipped, so it is neutral under AND and OR (negation has no effect)
+                None => continue,
+            };
+            degrees.push(deg);
+        }
+
+        // 1.0 and 0.0 are the neutral elements of every t-norm and s-norm
+        let fire = match rule.connective {
+            Connective::And => degrees
+                .into_iter()
+                .fold(F::ONE, |a, d| self.and_method.apply(a, d)),
+            Connective::Or => degrees
+                .into_iter()
+                .fold(F::ZERO, |a, d| self.or_method.apply(a, d)),
+        };
+        Ok(fire * weight)
+    }
+
+    // Degree of truth of an antecedent expression tree
+    fn evaluate_expression(
+        &self,
+        expression: &Expression,
+        crisp_inputs: &[F],
+    ) -> Result<F, FisError> {
+        match expression {
+            Expression::Is { variable, term } => {
+                let i = self
+                    .inputs
+                    .iter()
+                    .position(|v| &v.name == variable)
+                    .ok_or_else(|| FisError::VariableNotFound(variable.clone()))?;
+                let term = self.inputs[i]
+                    .term(term)
+                    .ok_or_else(|| FisError::TermNotFound(term.clone()))?;
+                Ok(term.degree(crisp_inputs[i]))
+            }
+            Expression::Not(inner) => {
+                let mu = self.evaluate_expression(inner, crisp_inputs)?;
+                Ok(self.complement.apply(mu))
+            }
+            Expression::And(operands) => operands.iter().try_fold(F::ONE, |a, e| {
+                let d = self.evaluate_expression(e, crisp_inputs)?;
+                Ok(self.and_method.apply(a, d))
+            }),
+            Expression::Or(operands) => operands.iter().try_fold(F::ZERO, |a, e| {
+                let d = self.evaluate_expression(e, crisp_inputs)?;
+                Ok(self.or_method.apply(a, d))
+            }),
+        }
+    }
 
-                    let fire: f64 = match rule.connective {
-                        Connective::And => degrees.into_iter().fold(1.0, |a, d| a.min(d)), // min
-                        Connective::Or => degrees.into_iter().fold(0.0, |a, d| a.max(d)),  // max
-                    };
-
-                    // Apply to consequent terms of the current output
-                    // We allow multiple outputs; pick the term that belongs to current out var if present
-                    let cons_term_name_opt = rule.consequent.get(out_idx).cloned();
-                    if let Some(cons_term_name) = cons_term_name_opt {
-                        if let Some(term) = out_var.term(&cons_term_name) {
-                            // Aggregate: max between existing agg and clipped term curve
-                            for (j, x) in xs.iter().enumerate() {
-                                let mu = term.degree(*x);
-                                let clipped = fire.min(mu);
-                                if clipped > agg[j] {
-                                    agg[j] = clipped;
-                                }
-                            }
-                        } else {
-                            return Err(FisError::TermNotFound(cons_term_name));
+    fn compute_mamdani(&self, crisp_inputs: &[F]) -> Result<Vec<F>, FisError> {
+        // For each output variable, aggregate membership over rule implications
+        let mut outputs_crisp = Vec::with_capacity(self.outputs.len());
+
+        for (out_idx, out_var) in self.outputs.iter().enumerate() {
+            // Initialize aggregated membership curve across discretized range
+            let xs = linspace(out_var.range.min, out_var.range.max, self.resolution);
+            let mut agg: Vec<F> = vec![F::ZERO; xs.len()];
+            // Firing-strength-weighted sum of consequent peaks for weighted average
+            let mut peak_num = F::ZERO;
+            let mut peak_den = F::ZERO;
+
+            // Centroid of piecewise linear terms or singletons is computed exactly instead of on the grid
+            let defuzzifier = out_var.defuzzifier.as_ref().unwrap_or(&self.defuzzifier);
+            let kinds = out_var.terms.iter().map(|t| &t.kind);
+            let singletons =
+                matches!(defuzzifier, Defuzzifier::Centroid) && are_singletons(kinds.clone());
+            let knots = match defuzzifier {
+                Defuzzifier::Centroid if is_piecewise_linear(kinds.clone(), self.aggregation) => {
+                    Some(centroid_knots(out_var.range, kinds))
+                }
+                _ => None,
+            };
+            let mut implied = Vec::new();
+
+            for rule in &self.rules {
+                let fire = self.firing_strength(rule, crisp_inputs)?;
+
+                // Apply to consequent terms of the current output
+                // We allow multiple outputs; pick the term that belongs to current out var if present
+                // An empty term name leaves the output untouched by the rule
+                let cons_term_name_opt = rule
+                    .consequent
+                    .get(out_idx)
+                    .filter(|name| !name.is_empty())
+                    .cloned();
+                if let Some(cons_term_name) = cons_term_name_opt {
+                    if let Some(term) = out_var.term(&cons_term_name) {
+                        if let Some(peak) = term.kind.peak() {
+                            peak_num += fire * peak;
+                            peak_den += fire;
                         }
+                        if singletons || knots.is_some() {
+                            implied.push((fire, &term.kind));
+                            continue;
+                        }
+                        // Aggregate: combine existing agg with the implied term curve
+                        for (j, x) in xs.iter().enumerate() {
+                            let implied = self.implication.apply(fire, term.degree(*x));
+                            agg[j] = self.aggregation.apply(agg[j], implied);
+                        }
+                    } else {
+                        return Err(FisError::TermNotFound(cons_term_name));
                     }
                 }
+            }
 
-                let centroid = centroid(&xs, &agg);
-                outputs_crisp.push(centroid);
+            self.aggregation.normalize(&mut agg);
+
+            let exact = if singletons {
+                singleton_centroid(implied.iter().copied(), self.implication, self.aggregation)
+            } else {
+                knots.and_then(|knots| {
+                    piecewise_linear_centroid(
+                        knots,
+                        implied.iter().copied(),
+                        self.implication,
+                        self.aggregation,
+                        &mut vec![[F::ZERO; 3]; implied.len()],
+                        &mut vec![F::ZERO; implied.len()],
+                    )
+                })
+            };
+            let crisp = match (defuzzifier, exact) {
+                (Defuzzifier::WeightedAverage, _) if peak_den > F::ZERO => peak_num / peak_den,
+                (_, Some(centroid)) => centroid,
+                // sampled, or nothing fired (the empty grid gives the usual fallback)
+                (other, None) => other.defuzzify(&xs, &agg),
+            };
+            outputs_crisp.push(crisp);
+        }
+
+        // return kind of defuzzified values as vector results?!
+        Ok(outputs_crisp)
+    }
+
+    // Takagi-Sugeno-Kang inference: every output is the firing-strength-weighted
+    // average of its rule consequents (constant or linear in the crisp inputs)
+    fn compute_sugeno(&self, crisp_inputs: &[F]) -> Result<Vec<F>, FisError> {
+        let mut outputs_crisp = Vec::with_capacity(self.outputs.len());
+
+        for (out_idx, out_var) in self.outputs.iter().enumerate() {
+            let mut num = F::ZERO;
+            let mut den = F::ZERO;
+
+            for rule in &self.rules {
+                let fire = self.firing_strength(rule, crisp_inputs)?;
+
+                if let Some(cons_term_name) =
+                    rule.consequent.get(out_idx).filter(|name| !name.is_empty())
+                {
+                    let term = out_var
+                        .term(cons_term_name)
+                        .ok_or_else(|| FisError::TermNotFound(cons_term_name.clone()))?;
+                    let z = term
+                        .kind
+                        .evaluate(crisp_inputs)
+                        .ok_or_else(|| FisError::InvalidSugenoTerm(cons_term_name.clone()))?;
+                    num += fire * z;
+                    den += fire;
+                }
+            }
+
+            // No rule fired: fall back to the middle of the output range
+            if den == F::ZERO {
+                outputs_crisp.push((out_var.range.min + out_var.range.max) / F::TWO);
+            } else {
+                outputs_crisp.push(num / den);
+            }
+        }
+
+        Ok(outputs_crisp)
+    }
+
+    // Tsukamoto inference: every rule consequent is a monotonic term inverted at
+    // the rule firing strength, outputs are the weighted average of those values
+    fn compute_tsukamoto(&self, crisp_inputs: &[F]) -> Result<Vec<F>, FisError> {
+        let mut outputs_crisp = Vec::with_capacity(self.outputs.len());
+
+        for (out_idx, out_var) in self.outputs.iter().enumerate() {
+            let mut num = F::ZERO;
+            let mut den = F::ZERO;
+
+            for rule in &self.rules {
+                let fire = self.firing_strength(rule, crisp_inputs)?;
+
+                if let Some(cons_term_name) =
+                    rule.consequent.get(out_idx).filter(|name| !name.is_empty())
+                {
+                    let term = out_var
+                        .term(cons_term_name)
+                        .ok_or_else(|| FisError::TermNotFound(cons_term_name.clone()))?;
+                    let z = term
+                        .kind
+                        .inverse(fire)
+                        .ok_or_else(|| FisError::NonMonotonicTerm(cons_term_name.clone()))?;
+                    num += fire * z;
+                    den += fire;
+                }
             }
 
-            // return kind of centroids as vector results?!
-            return Ok(outputs_crisp);
+            // No rule fired: fall back to the middle of the output range
+            if den == F::ZERO {
+                outputs_crisp.push((out_var.range.min + out_var.range.max) / F::TWO);
+            } else {
+                outputs_crisp.push(num / den);
+            }
         }
 
-        Err(FisError::UndefinedFuzzyInferenceSystemType)
+        Ok(outputs_crisp)
     }
 
     // Compute outputs and return more descriptive output in OutputResult structure
     pub fn compute_verbose(
         &self,
         fis_type: FisType,
-        crisp_inputs: &[f64],
-    ) -> Result<Vec<OutputResult>, FisError> {
+        crisp_inputs: &[F],
+    ) -> Result<Vec<OutputResult<F>>, FisError> {
         let result = self.compute(fis_type, crisp_inputs);
 
         match result {
-            Err(error) => {
-                return Err(error);
-            }
-            Ok(ref other) => {
+            Err(error) => Err(error),
+            Ok(_) => {
                 let mut results = Vec::new();
 
                 for (out_var, crisp_value) in self.outputs.iter().zip(result.iter()) {
                     // Find the best matching term
                     let mut best_term = None;
-                    let mut best_mu = -1.0;
+                    let mut best_mu = -F::ONE;
                     let mut term_kind = None;
 
                     for term in &out_var.terms {
@@ -158,7 +493,7 @@ impl FuzzyInferenceSystem {
 
                     results.push(OutputResult {
                         variable_name: out_var.name.clone(),
-                        range: out_var.range.clone(),
+                        range: out_var.range,
                         value: crisp_value.clone(),
                         best_term,
                         term_kind,
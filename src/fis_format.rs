@@ -0,0 +1,717 @@
+/*
+MATLAB / Octave .fis text format.
+
+[System]
+Name='tipper'
+Type='mamdani'
+Version=2.0
+NumInputs=2
+NumOutputs=1
+NumRules=2
+AndMethod='min'
+OrMethod='max'
+ImpMethod='min'
+AggMethod='max'
+DefuzzMethod='centroid'
+
+[Input1]
+Name='service'
+Range=[0 10]
+NumMFs=2
+MF1='poor':'trimf',[0 0 5]
+MF2='good':'trimf',[0 5 10]
+
+[Output1]
+...
+
+[Rules]
+1 1, 1 (1) : 1       <- input term indices, output term indices (weight) : 1 = AND, 2 = OR
+
+Term indices are 1-based, 0 is a wildcard (or "no consequent") and a negative
+index negates the antecedent clause. Membership functions trimf, trapmf, linzmf /
+linsmf (left / right shoulders), gaussmf, gauss2mf, gbellmf, sigmf, dsigmf, psigmf,
+smf, zmf, pimf and the Sugeno constant / linear consequents are supported.
+
+Every section needs a Name (and variables a Range). The counts NumInputs,
+NumOutputs, NumRules and NumMFs are optional, but must match the parsed sections
+when given; mismatches are reported on the line of the count.
+*/
+use crate::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    float::Float,
+    membership::MembershipKind,
+    operators::{Aggregation, Implication, SNorm, TNorm},
+    rule::{Connective, Rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+fn format_error(line: usize, message: impl Into<String>) -> FisError {
+    FisError::FisFormat {
+        line,
+        message: message.into(),
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('\'')
+}
+
+fn parse_numbers(line: usize, text: &str) -> Result<Vec<f64>, FisError> {
+    text.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<f64>()
+                .map_err(|_| format_error(line, format!("invalid number '{}'", s)))
+        })
+        .collect()
+}
+
+fn parse_membership<F: Float>(
+    line: usize,
+    mf_type: &str,
+    params: &[f64],
+) -> Result<MembershipKind<F>, FisError> {
+    let param = |i: usize| F::from_f64(params[i]);
+    let expect = |n: usize| {
+        if params.len() == n {
+            Ok(())
+        } else {
+            Err(format_error(
+                line,
+                format!(
+                    "'{}' expects {} parameters, got {}",
+                    mf_type,
+                    n,
+                    params.len()
+                ),
+            ))
+        }
+    };
+    match mf_type {
+        "trimf" => {
+            expect(3)?;
+            Ok(MembershipKind::Triangle {
+                a: param(0),
+                b: param(1),
+                c: param(2),
+            })
+        }
+        "trapmf" => {
+            expect(4)?;
+            Ok(MembershipKind::Trapezoid {
+                a: param(0),
+                b: param(1),
+                c: param(2),
+                d: param(3),
+            })
+        }
+        "linzmf" => {
+            expect(2)?;
+            Ok(MembershipKind::LeftShoulder {
+                a: param(0),
+                b: param(1),
+            })
+        }
+        "linsmf" => {
+            expect(2)?;
+            Ok(MembershipKind::RightShoulder {
+                a: param(0),
+                b: param(1),
+            })
+        }
+        "gaussmf" => {
+            expect(2)?;
+            Ok(MembershipKind::Gauss {
+                sigma: param(0),
+                mu: param(1),
+            })
+        }
+        "gbellmf" => {
+            expect(3)?;
+            Ok(MembershipKind::Bell {
+                a: param(0),
+                b: param(1),
+                c: param(2),
+            })
+        }
+        "sigmf" => {
+            expect(2)?;
+            Ok(MembershipKind::Sigmoid {
+                a: param(0),
+                c: param(1),
+            })
+        }
+        "dsigmf" => {
+            expect(4)?;
+            Ok(MembershipKind::DiffSigmoid {
+                a1: param(0),
+                c1: param(1),
+                a2: param(2),
+                c2: param(3),
+            })
+        }
+        "psigmf" => {
+            expect(4)?;
+            Ok(MembershipKind::ProdSigmoid {
+                a1: param(0),
+                c1: param(1),
+                a2: param(2),
+                c2: param(3),
+            })
+        }
+        "smf" => {
+            expect(2)?;
+            Ok(MembershipKind::SShape {
+                a: param(0),
+                b: param(1),
+            })
+        }
+        "zmf" => {
+            expect(2)?;
+            Ok(MembershipKind::ZShape {
+                a: param(0),
+                b: param(1),
+            })
+        }
+        "pimf" => {
+            expect(4)?;
+            Ok(MembershipKind::PiShape {
+                a: param(0),
+                b: param(1),
+                c: param(2),
+                d: param(3),
+            })
+        }
+        "gauss2mf" => {
+            expect(4)?;
+            Ok(MembershipKind::Gauss2 {
+                sigma1: param(0),
+                mu1: param(1),
+                sigma2: param(2),
+                mu2: param(3),
+            })
+        }
+        "constant" => {
+            expect(1)?;
+            Ok(MembershipKind::Constant { value: param(0) })
+        }
+        "linear" => match params.split_last() {
+            Some((constant, coefficients)) => Ok(MembershipKind::Linear {
+                coefficients: coefficients.iter().map(|c| F::from_f64(*c)).collect(),
+                constant: F::from_f64(*constant),
+            }),
+            None => Err(format_error(line, "'linear' expects parameters")),
+        },
+        other => Err(FisError::UnsupportedFisConstruct(format!(
+            "membership function type '{}'",
+            other
+        ))),
+    }
+}
+
+fn membership_to_fis<F: Float>(
+    kind: &MembershipKind<F>,
+) -> Result<(&'static str, Vec<F>), FisError> {
+    match kind {
+        MembershipKind::Triangle { a, b, c } => Ok(("trimf", vec![*a, *b, *c])),
+        MembershipKind::Trapezoid { a, b, c, d } => Ok(("trapmf", vec![*a, *b, *c, *d])),
+        MembershipKind::LeftShoulder { a, b } => Ok(("linzmf", vec![*a, *b])),
+        MembershipKind::RightShoulder { a, b } => Ok(("linsmf", vec![*a, *b])),
+        MembershipKind::Gauss { sigma, mu } => Ok(("gaussmf", vec![*sigma, *mu])),
+        MembershipKind::Bell { a, b, c } => Ok(("gbellmf", vec![*a, *b, *c])),
+        MembershipKind::Sigmoid { a, c } => Ok(("sigmf", vec![*a, *c])),
+        MembershipKind::DiffSigmoid { a1, c1, a2, c2 } => Ok(("dsigmf", vec![*a1, *c1, *a2, *c2])),
+        MembershipKind::ProdSigmoid { a1, c1, a2, c2 } => Ok(("psigmf", vec![*a1, *c1, *a2, *c2])),
+        MembershipKind::SShape { a, b } => Ok(("smf", vec![*a, *b])),
+        MembershipKind::ZShape { a, b } => Ok(("zmf", vec![*a, *b])),
+        MembershipKind::PiShape { a, b, c, d } => Ok(("pimf", vec![*a, *b, *c, *d])),
+        MembershipKind::Gauss2 {
+            sigma1,
+            mu1,
+            sigma2,
+            mu2,
+        } => Ok(("gauss2mf", vec![*sigma1, *mu1, *sigma2, *mu2])),
+        MembershipKind::Polygon { .. } | MembershipKind::Sampled { .. } => Err(
+            FisError::UnsupportedFisConstruct("point list membership function".to_string()),
+        ),
+        MembershipKind::Custom(membership) => Err(FisError::UnsupportedFisConstruct(format!(
+            "custom membership function {:?}",
+            membership
+        ))),
+        // a crisp point in either system type
+        MembershipKind::Singleton { value } | MembershipKind::Constant { value } => {
+            Ok(("constant", vec![*value]))
+        }
+        MembershipKind::Linear {
+            coefficients,
+            constant,
+        } => {
+            let mut params = coefficients.clone();
+            params.push(*constant);
+            Ok(("linear", params))
+        }
+    }
+}
+
+// MF1='poor':'trimf',[0 0 5]
+fn parse_mf_line<F: Float>(line: usize, value: &str) -> Result<Term<F>, FisError> {
+    let (name, rest) = value
+        .split_once(':')
+        .ok_or_else(|| format_error(line, "expected 'name':'type',[params]"))?;
+    let (mf_type, params) = rest
+        .split_once(',')
+        .ok_or_else(|| format_error(line, "expected 'name':'type',[params]"))?;
+    let params = parse_numbers(line, params)?;
+    let kind = parse_membership(line, unquote(mf_type), &params)?;
+    Ok(Term::new(unquote(name), kind))
+}
+
+#[derive(Default)]
+struct Section {
+    // line of the [header], for missing entries
+    line: usize,
+    header: String,
+    entries: Vec<(usize, String, String)>,
+    lines: Vec<(usize, String)>,
+}
+
+impl Section {
+    fn get(&self, key: &str) -> Option<(usize, &str)> {
+        self.entries
+            .iter()
+            .find(|(_, k, _)| k == key)
+            .map(|(line, _, v)| (*line, v.as_str()))
+    }
+
+    fn require(&self, key: &str) -> Result<(usize, &str), FisError> {
+        self.get(key)
+            .ok_or_else(|| format_error(self.line, format!("missing {} in [{}]", key, self.header)))
+    }
+
+    // Check a declared count (NumInputs=2, NumMFs=3, ...) against what was parsed
+    fn check_count(&self, key: &str, found: usize) -> Result<(), FisError> {
+        let Some((line, value)) = self.get(key) else {
+            return Ok(());
+        };
+        match value.parse::<usize>() {
+            Ok(declared) if declared == found => Ok(()),
+            Ok(declared) => Err(format_error(
+                line,
+                format!("{} declares {}, found {}", key, declared, found),
+            )),
+            Err(_) => Err(format_error(line, format!("invalid {} '{}'", key, value))),
+        }
+    }
+}
+
+fn parse_variable<F: Float>(section: &Section) -> Result<LinguisticVariable<F>, FisError> {
+    let (_, name) = section.require("Name")?;
+    let (line, range) = section.require("Range")?;
+    let range = parse_numbers(line, range)?;
+    if range.len() != 2 {
+        return Err(format_error(line, "Range expects [min max]"));
+    }
+
+    let mut var = LinguisticVariable::new(
+        unquote(name),
+        Range {
+            min: F::from_f64(range[0]),
+            max: F::from_f64(range[1]),
+        },
+    );
+    for (line, key, value) in &section.entries {
+        if key.starts_with("MF") {
+            var.add_term(parse_mf_line(*line, value)?)?;
+        }
+    }
+    section.check_count("NumMFs", var.terms.len())?;
+    Ok(var)
+}
+
+fn term_index<F: Float>(
+    line: usize,
+    var: &LinguisticVariable<F>,
+    index: i64,
+) -> Result<String, FisError> {
+    var.terms
+        .get(index.unsigned_abs() as usize - 1)
+        .map(|t| t.name.clone())
+        .ok_or_else(|| {
+            format_error(
+                line,
+                format!("term index {} out of range for '{}'", index, var.name),
+            )
+        })
+}
+
+// 1 -2, 1 0 (0.5) : 1
+fn parse_rule_line<F: Float>(
+    line: usize,
+    text: &str,
+    inputs: &[LinguisticVariable<F>],
+    outputs: &[LinguisticVariable<F>],
+) -> Result<Rule, FisError> {
+    let (indices, connective) = text
+        .split_once(':')
+        .ok_or_else(|| format_error(line, "expected ': <connective>' in rule"))?;
+    let connective = match connective.trim() {
+        "1" => Connective::And,
+        "2" => Connective::Or,
+        other => {
+            return Err(format_error(
+                line,
+                format!("invalid connective '{}'", other),
+            ))
+        }
+    };
+    let (indices, weight) = indices
+        .split_once('(')
+        .ok_or_else(|| format_error(line, "expected '(weight)' in rule"))?;
+    let weight: f64 = weight
+        .trim()
+        .trim_end_matches(')')
+        .trim()
+        .parse()
+        .map_err(|_| format_error(line, "invalid rule weight"))?;
+    if !(0.0..=1.0).contains(&weight) {
+        return Err(format_error(line, "rule weight must be within [0, 1]"));
+    }
+
+    let numbers: Vec<i64> = indices
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<f64>()
+                .map(|n| n as i64)
+                .map_err(|_| format_error(line, format!("invalid index '{}'", s)))
+        })
+        .collect::<Result<_, _>>()?;
+    if numbers.len() != inputs.len() + outputs.len() {
+        return Err(format_error(
+            line,
+            format!(
+                "expected {} term indices, got {}",
+                inputs.len() + outputs.len(),
+                numbers.len()
+            ),
+        ));
+    }
+
+    let mut antecedent = Vec::with_capacity(inputs.len());
+    let mut negated = vec![false; inputs.len()];
+    for (i, (var, index)) in inputs.iter().zip(&numbers[..inputs.len()]).enumerate() {
+        if *index == 0 {
+            antecedent.push(None);
+        } else {
+            antecedent.push(Some(term_index(line, var, *index)?));
+            negated[i] = *index < 0;
+        }
+    }
+
+    let mut consequent = Vec::with_capacity(outputs.len());
+    for (var, index) in outputs.iter().zip(&numbers[inputs.len()..]) {
+        if *index < 0 {
+            return Err(FisError::UnsupportedFisConstruct(
+                "negated rule consequent".to_string(),
+            ));
+        } else if *index == 0 {
+            consequent.push(String::new());
+        } else {
+            consequent.push(term_index(line, var, *index)?);
+        }
+    }
+
+    let mut rule = Rule::new(antecedent, consequent, connective).with_weight(weight)?;
+    if negated.iter().any(|n| *n) {
+        rule.negated = negated;
+    }
+    Ok(rule)
+}
+
+fn unsupported_method(key: &str, value: &str) -> FisError {
+    FisError::UnsupportedFisConstruct(format!("{} '{}'", key, value))
+}
+
+// Build a system from .fis text, together with the inference type it declares
+pub fn from_fis_str<F: Float>(text: &str) -> Result<(FuzzyInferenceSystem<F>, FisType), FisError> {
+    let mut sections: Vec<Section> = Vec::new();
+    for (i, raw) in text.lines().enumerate() {
+        let line = i + 1;
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            sections.push(Section {
+                line,
+                header: trimmed[1..trimmed.len() - 1].to_string(),
+                ..Section::default()
+            });
+            continue;
+        }
+        let section = sections
+            .last_mut()
+            .ok_or_else(|| format_error(line, "content before the first [section]"))?;
+        if section.header == "Rules" {
+            section.lines.push((line, trimmed.to_string()));
+        } else {
+            let (key, value) = trimmed
+                .split_once('=')
+                .ok_or_else(|| format_error(line, "expected key=value"))?;
+            section
+                .entries
+                .push((line, key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let system = sections
+        .iter()
+        .find(|s| s.header == "System")
+        .ok_or_else(|| format_error(0, "missing [System] section"))?;
+    let (_, name) = system.require("Name")?;
+    let mut fis = FuzzyInferenceSystem::new(unquote(name));
+
+    let fis_type = match system.get("Type").map(|(_, v)| unquote(v)) {
+        Some("mamdani") | None => FisType::Mamdani,
+        Some("sugeno") => FisType::Sugeno,
+        Some(other) => return Err(unsupported_method("Type", other)),
+    };
+
+    if let Some((_, v)) = system.get("AndMethod") {
+        fis.and_method = match unquote(v) {
+            "min" => TNorm::Min,
+            "prod" => TNorm::Product,
+            other => return Err(unsupported_method("AndMethod", other)),
+        };
+    }
+    if let Some((_, v)) = system.get("OrMethod") {
+        fis.or_method = match unquote(v) {
+            "max" => SNorm::Max,
+            "probor" => SNorm::ProbabilisticSum,
+            other => return Err(unsupported_method("OrMethod", other)),
+        };
+    }
+    if let Some((_, v)) = system.get("ImpMethod") {
+        fis.implication = match unquote(v) {
+            "min" => Implication::Min,
+            "prod" => Implication::Product,
+            other => return Err(unsupported_method("ImpMethod", other)),
+        };
+    }
+    if let Some((_, v)) = system.get("AggMethod") {
+        fis.aggregation = match unquote(v) {
+            "max" => Aggregation::Max,
+            "probor" => Aggregation::ProbabilisticSum,
+            // scaling does not move any of the defuzzified points
+            "sum" => Aggregation::NormalizedSum,
+            other => return Err(unsupported_method("AggMethod", other)),
+        };
+    }
+    if let Some((_, v)) = system.get("DefuzzMethod") {
+        fis.defuzzifier = match unquote(v) {
+            "centroid" => Defuzzifier::Centroid,
+            "bisector" => Defuzzifier::Bisector,
+            "mom" => Defuzzifier::MeanOfMaximum,
+            "som" => Defuzzifier::SmallestOfMaximum,
+            "lom" => Defuzzifier::LargestOfMaximum,
+            "wtaver" => Defuzzifier::WeightedAverage,
+            other => return Err(unsupported_method("DefuzzMethod", other)),
+        };
+    }
+
+    let numbered = |prefix: &str| {
+        let mut vars: Vec<(usize, &Section)> = sections
+            .iter()
+            .filter_map(|s| {
+                s.header
+                    .strip_prefix(prefix)
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .map(|n| (n, s))
+            })
+            .collect();
+        vars.sort_by_key(|(n, _)| *n);
+        vars
+    };
+    for (_, section) in numbered("Input") {
+        fis.add_input(parse_variable(section)?);
+    }
+    for (_, section) in numbered("Output") {
+        fis.add_output(parse_variable(section)?);
+    }
+    system.check_count("NumInputs", fis.inputs.len())?;
+    system.check_count("NumOutputs", fis.outputs.len())?;
+
+    if let Some(rules) = sections.iter().find(|s| s.header == "Rules") {
+        for (line, text) in &rules.lines {
+            let rule = parse_rule_line(*line, text, &fis.inputs, &fis.outputs)?;
+            fis.rules.push(rule);
+        }
+    }
+    system.check_count("NumRules", fis.rules.len())?;
+
+    Ok((fis, fis_type))
+}
+
+fn format_numbers<F: Float>(values: &[F]) -> String {
+    let parts: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", parts.join(" "))
+}
+
+fn term_position<F: Float>(var: &LinguisticVariable<F>, name: &str) -> Result<usize, FisError> {
+    var.terms
+        .iter()
+        .position(|t| t.name == name)
+        .map(|i| i + 1)
+        .ok_or_else(|| FisError::TermNotFound(name.to_string()))
+}
+
+fn write_variable<F: Float>(
+    out: &mut String,
+    header: &str,
+    var: &LinguisticVariable<F>,
+) -> Result<(), FisError> {
+    out.push_str(&format!("\n[{}]\n", header));
+    out.push_str(&format!("Name='{}'\n", var.name));
+    out.push_str(&format!(
+        "Range={}\n",
+        format_numbers(&[var.range.min, var.range.max])
+    ));
+    out.push_str(&format!("NumMFs={}\n", var.terms.len()));
+    for (i, term) in var.terms.iter().enumerate() {
+        let (mf_type, params) = membership_to_fis(&term.kind)?;
+        out.push_str(&format!(
+            "MF{}='{}':'{}',{}\n",
+            i + 1,
+            term.name,
+            mf_type,
+            format_numbers(&params)
+        ));
+    }
+    Ok(())
+}
+
+// Serialize a system as .fis text for the given inference type
+pub fn to_fis_string<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    fis_type: FisType,
+) -> Result<String, FisError> {
+    let type_name = match fis_type {
+        FisType::Mamdani => "mamdani",
+        FisType::Sugeno => "sugeno",
+        FisType::Tsukamoto => return Err(unsupported_method("Type", "tsukamoto")),
+    };
+    let and_method = match fis.and_method {
+        TNorm::Min => "min",
+        TNorm::Product => "prod",
+        other => return Err(unsupported_method("AndMethod", &format!("{:?}", other))),
+    };
+    let or_method = match fis.or_method {
+        SNorm::Max => "max",
+        SNorm::ProbabilisticSum => "probor",
+        other => return Err(unsupported_method("OrMethod", &format!("{:?}", other))),
+    };
+    let imp_method = match fis.implication {
+        Implication::Min => "min",
+        Implication::Product => "prod",
+        other => return Err(unsupported_method("ImpMethod", &format!("{:?}", other))),
+    };
+    let agg_method = match fis.aggregation {
+        Aggregation::Max => "max",
+        Aggregation::ProbabilisticSum => "probor",
+        Aggregation::NormalizedSum => "sum",
+        other => return Err(unsupported_method("AggMethod", &format!("{:?}", other))),
+    };
+    let defuzz_method = match (&fis.defuzzifier, fis_type) {
+        (_, FisType::Sugeno) => "wtaver",
+        (Defuzzifier::Centroid, _) => "centroid",
+        (Defuzzifier::Bisector, _) => "bisector",
+        (Defuzzifier::MeanOfMaximum, _) => "mom",
+        (Defuzzifier::SmallestOfMaximum, _) => "som",
+        (Defuzzifier::LargestOfMaximum, _) => "lom",
+        (Defuzzifier::WeightedAverage, _) => "wtaver",
+        (other, _) => return Err(unsupported_method("DefuzzMethod", &format!("{:?}", other))),
+    };
+    if fis.outputs.iter().any(|o| o.defuzzifier.is_some()) {
+        return Err(FisError::UnsupportedFisConstruct(
+            "per-output defuzzifier".to_string(),
+        ));
+    }
+
+    let mut out = String::new();
+    out.push_str("[System]\n");
+    out.push_str(&format!("Name='{}'\n", fis.name));
+    out.push_str(&format!("Type='{}'\n", type_name));
+    out.push_str("Version=2.0\n");
+    out.push_str(&format!("NumInputs={}\n", fis.inputs.len()));
+    out.push_str(&format!("NumOutputs={}\n", fis.outputs.len()));
+    out.push_str(&format!("NumRules={}\n", fis.rules.len()));
+    out.push_str(&format!("AndMethod='{}'\n", and_method));
+    out.push_str(&format!("OrMethod='{}'\n", or_method));
+    out.push_str(&format!("ImpMethod='{}'\n", imp_method));
+    out.push_str(&format!("AggMethod='{}'\n", agg_method));
+    out.push_str(&format!("DefuzzMethod='{}'\n", defuzz_method));
+
+    for (i, var) in fis.inputs.iter().enumerate() {
+        write_variable(&mut out, &format!("Input{}", i + 1), var)?;
+    }
+    for (i, var) in fis.outputs.iter().enumerate() {
+        write_variable(&mut out, &format!("Output{}", i + 1), var)?;
+    }
+
+    out.push_str("\n[Rules]\n");
+    for (r, rule) in fis.rules.iter().enumerate() {
+        if rule.expression.is_some() {
+            return Err(FisError::UnsupportedFisConstruct(format!(
+                "expression-based rule {}",
+                r + 1
+            )));
+        }
+        let mut antecedent = Vec::with_capacity(fis.inputs.len());
+        for (i, var) in fis.inputs.iter().enumerate() {
+            let index = match rule.antecedent.get(i).cloned().flatten() {
+                Some(name) => {
+                    let index = term_position(var, &name)? as i64;
+                    if rule.is_negated(i) {
+                        -index
+                    } else {
+                        index
+                    }
+                }
+                None => 0,
+            };
+            antecedent.push(index.to_string());
+        }
+        let mut consequent = Vec::with_capacity(fis.outputs.len());
+        for (o, var) in fis.outputs.iter().enumerate() {
+            let index = match rule.consequent.get(o).filter(|n| !n.is_empty()) {
+                Some(name) => term_position(var, name)?,
+                None => 0,
+            };
+            consequent.push(index.to_string());
+        }
+        let connective = match rule.connective {
+            Connective::And => 1,
+            Connective::Or => 2,
+        };
+        out.push_str(&format!(
+            "{}, {} ({}) : {}\n",
+            antecedent.join(" "),
+            consequent.join(" "),
+            rule.weight,
+            connective
+        ));
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,462 @@
+/*
+Fixed-capacity, heap-free inference engine.
+
+Every dimension is a const generic, so a controller has a size known at compile
+time and needs no allocator (it builds without the `alloc` feature):
+
+    I: input variables     O: output variables     T: term slots per variable
+    R: rules               N: Mamdani output grid points (unused by the exact centroid)
+
+Terms are referenced by index, rules are positional (no expression trees):
+
+    static CONTROLLER: FixedFis<1, 1, 2, 2, 101> = FixedFis::new(
+        [FixedVariable::new(Range { min: 0.0, max: 40.0 }, [Some(COLD), Some(HOT)])],
+        [FixedVariable::new(Range { min: 0.0, max: 100.0 }, [Some(LOW), Some(HIGH)])],
+        [
+            FixedRule::new([Some(0)], [Some(1)], Connective::And),
+            FixedRule::new([Some(1)], [Some(0)], Connective::And),
+        ],
+    );
+
+    let [power] = CONTROLLER.compute(FisType::Mamdani, &[temperature])?;
+
+Term indices are checked by `FixedFis::new`, in a static that turns mistakes
+into compile errors. Rule weights and operator parameters are given in `F`
+(e.g. `Q16::from_f64(0.8)`), so evaluation does no float conversion; a const fn
+cannot compare generic floats, weights outside [0, 1] are reported by `compute`.
+
+The Mamdani output grid and the term curves sampled on it cannot be built by
+`new`: a const fn cannot call `Float` arithmetic on a generic `F` (trait methods
+are not const-callable on stable Rust), so a static system samples its terms on
+every call. A system that does not need to be a static can store them once:
+
+    let grid = controller.grid();   // [F; N] per output, [F; N] per output term
+    let [power] = controller.compute_on(&grid, FisType::Mamdani, &[temperature])?;
+*/
+
+use core::mem;
+
+use crate::{
+    defuzzifier::Defuzzifier,
+    fis::FisType,
+    float::Float,
+    math_helpers::{
+        are_singletons, is_piecewise_linear, piecewise_linear_centroid, singleton_centroid,
+    },
+    membership::MembershipKind,
+    operators::{Aggregation, Complement, Implication, SNorm, TNorm},
+    rule::Connective,
+    variable::Range,
+};
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+pub enum FixedFisError {
+    #[error("invalid Sugeno consequent: output {output}, term {term}")]
+    InvalidSugenoTerm { output: usize, term: usize },
+    #[error("non-monotonic Tsukamoto consequent: output {output}, term {term}")]
+    NonMonotonicTerm { output: usize, term: usize },
+    #[error("rule weight must be within [0, 1]: rule {rule}")]
+    InvalidRuleWeight { rule: usize },
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedVariable<const T: usize, F: Float = f64> {
+    pub range: Range<F>,
+    // term slots, None = unused
+    pub terms: [Option<MembershipKind<F>>; T],
+}
+
+impl<const T: usize, F: Float> FixedVariable<T, F> {
+    pub const fn new(range: Range<F>, terms: [Option<MembershipKind<F>>; T]) -> Self {
+        Self { range, terms }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FixedRule<const I: usize, const O: usize, F: Float = f64> {
+    pub antecedent: [Option<usize>; I], // term index per input variable (None = wildcard)
+    pub consequent: [Option<usize>; O], // term index per output variable (None = none)
+    pub connective: Connective,         // connective type: And, Or
+    pub weight: F,                      // certainty factor in [0, 1] scaling the firing strength
+    pub negated: [bool; I],             // per input variable: true = IS NOT
+}
+
+impl<const I: usize, const O: usize, F: Float> FixedRule<I, O, F> {
+    pub const fn new(
+        antecedent: [Option<usize>; I],
+        consequent: [Option<usize>; O],
+        connective: Connective,
+    ) -> Self {
+        Self {
+            antecedent,
+            consequent,
+            connective,
+            weight: F::ONE,
+            negated: [false; I],
+        }
+    }
+
+    pub const fn with_weight(mut self, weight: F) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub const fn negate(mut self, input: usize) -> Self {
+        self.negated[input] = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedFis<
+    const I: usize,
+    const O: usize,
+    const T: usize,
+    const R: usize,
+    const N: usize,
+    F: Float = f64,
+> {
+    inputs: [FixedVariable<T, F>; I],
+    outputs: [FixedVariable<T, F>; O],
+    rules: [FixedRule<I, O, F>; R],
+    defuzzifier: Defuzzifier<F>,
+    and_method: TNorm<F>,
+    or_method: SNorm<F>,
+    implication: Implication,
+    aggregation: Aggregation,
+    complement: Complement<F>,
+}
+
+// Mamdani output grid and every output term sampled on it, see `FixedFis::grid`
+#[derive(Debug, Clone)]
+pub struct FixedGrid<const O: usize, const T: usize, const N: usize, F: Float = f64> {
+    xs: [[F; N]; O],
+    curves: [[[F; N]; T]; O],
+}
+
+impl<const I: usize, const O: usize, const T: usize, const R: usize, const N: usize, F: Float>
+    FixedFis<I, O, T, R, N, F>
+{
+    // Panics (a compile error in a const or static) when a rule refers to an empty
+    // term slot, or N is zero
+    pub const fn new(
+        inputs: [FixedVariable<T, F>; I],
+        outputs: [FixedVariable<T, F>; O],
+        rules: [FixedRule<I, O, F>; R],
+    ) -> Self {
+        assert!(N > 0, "the output grid needs at least one point");
+
+        let mut r = 0;
+        while r < R {
+            let rule = &rules[r];
+            let mut i = 0;
+            while i < I {
+                if let Some(term) = rule.antecedent[i] {
+                    assert!(
+                        term < T && inputs[i].terms[term].is_some(),
+                        "rule antecedent refers to an undefined term"
+                    );
+                }
+                i += 1;
+            }
+            let mut o = 0;
+            while o < O {
+                if let Some(term) = rule.consequent[o] {
+                    assert!(
+                        term < T && outputs[o].terms[term].is_some(),
+                        "rule consequent refers to an undefined term"
+                    );
+                }
+                o += 1;
+            }
+            r += 1;
+        }
+
+        Self {
+            inputs,
+            outputs,
+            rules,
+            defuzzifier: Defuzzifier::Centroid,
+            and_method: TNorm::Min,
+            or_method: SNorm::Max,
+            implication: Implication::Min,
+            aggregation: Aggregation::Max,
+            complement: Complement::Standard,
+        }
+    }
+
+    pub fn inputs(&self) -> &[FixedVariable<T, F>; I] {
+        &self.inputs
+    }
+
+    pub fn outputs(&self) -> &[FixedVariable<T, F>; O] {
+        &self.outputs
+    }
+
+    pub fn rules(&self) -> &[FixedRule<I, O, F>; R] {
+        &self.rules
+    }
+
+    // (without `alloc` the defuzzifier owns nothing and forgetting it is a no-op)
+    #[allow(clippy::forget_non_drop)]
+    pub const fn with_defuzzifier(mut self, defuzzifier: Defuzzifier<F>) -> Self {
+        // const fn cannot run destructors, the replaced value is forgotten instead
+        mem::forget(mem::replace(&mut self.defuzzifier, defuzzifier));
+        self
+    }
+
+    pub const fn with_and_method(mut self, and_method: TNorm<F>) -> Self {
+        self.and_method = and_method;
+        self
+    }
+
+    pub const fn with_or_method(mut self, or_method: SNorm<F>) -> Self {
+        self.or_method = or_method;
+        self
+    }
+
+    pub const fn with_implication(mut self, implication: Implication) -> Self {
+        self.implication = implication;
+        self
+    }
+
+    pub const fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    pub const fn with_complement(mut self, complement: Complement<F>) -> Self {
+        self.complement = complement;
+        self
+    }
+
+    // Sample the output grids and output terms once, for `compute_on`
+    pub fn grid(&self) -> FixedGrid<O, T, N, F> {
+        let mut grid = FixedGrid {
+            xs: [[F::ZERO; N]; O],
+            curves: [[[F::ZERO; N]; T]; O],
+        };
+        for ((xs, curves), out_var) in grid.xs.iter_mut().zip(&mut grid.curves).zip(&self.outputs) {
+            *xs = grid_points(&out_var.range);
+            for (curve, kind) in curves.iter_mut().zip(&out_var.terms) {
+                let Some(kind) = kind else {
+                    continue;
+                };
+                for (mu, x) in curve.iter_mut().zip(xs.iter()) {
+                    *mu = kind.degree(*x);
+                }
+            }
+        }
+        grid
+    }
+
+    // Compute precise outputs using selected inference type, with all buffers on the stack
+    pub fn compute(
+        &self,
+        fis_type: FisType,
+        crisp_inputs: &[F; I],
+    ) -> Result<[F; O], FixedFisError> {
+        self.infer(None, fis_type, crisp_inputs)
+    }
+
+    // Same as `compute`, looking the Mamdani grid and term degrees up in `grid`
+    // (which must come from this system's `grid`) instead of sampling them
+    pub fn compute_on(
+        &self,
+        grid: &FixedGrid<O, T, N, F>,
+        fis_type: FisType,
+        crisp_inputs: &[F; I],
+    ) -> Result<[F; O], FixedFisError> {
+        self.infer(Some(grid), fis_type, crisp_inputs)
+    }
+
+    fn infer(
+        &self,
+        grid: Option<&FixedGrid<O, T, N, F>>,
+        fis_type: FisType,
+        crisp_inputs: &[F; I],
+    ) -> Result<[F; O], FixedFisError> {
+        let mut fire = [F::ZERO; R];
+        for (r, (fire, rule)) in fire.iter_mut().zip(&self.rules).enumerate() {
+            if !(rule.weight >= F::ZERO && rule.weight <= F::ONE) {
+                return Err(FixedFisError::InvalidRuleWeight { rule: r });
+            }
+            *fire = self.firing_strength(rule, crisp_inputs);
+        }
+
+        let mut outputs_crisp = [F::ZERO; O];
+        for (out_idx, crisp) in outputs_crisp.iter_mut().enumerate() {
+            *crisp = match fis_type {
+                FisType::Mamdani => self.defuzzify_mamdani(grid, out_idx, &fire),
+                FisType::Sugeno | FisType::Tsukamoto => {
+                    self.weighted_average(fis_type, out_idx, &fire, crisp_inputs)?
+                }
+            };
+        }
+        Ok(outputs_crisp)
+    }
+
+    fn term(var: &FixedVariable<T, F>, term: usize) -> &MembershipKind<F> {
+        var.terms[term]
+            .as_ref()
+            .expect("term indices are checked by FixedFis::new")
+    }
+
+    fn firing_strength(&self, rule: &FixedRule<I, O, F>, crisp_inputs: &[F; I]) -> F {
+        let degrees = rule
+            .antecedent
+            .iter()
+            .enumerate()
+            // wildcards are skipped, so they are neutral under AND and OR (negation has no effect)
+            .filter_map(|(i, term)| {
+                let mu = Self::term(&self.inputs[i], (*term)?).degree(crisp_inputs[i]);
+                Some(if rule.negated[i] {
+                    self.complement.apply(mu)
+                } else {
+                    mu
+                })
+            });
+
+        // 1.0 and 0.0 are the neutral elements of every t-norm and s-norm
+        let fire = match rule.connective {
+            Connective::And => degrees.fold(F::ONE, |a, d| self.and_method.apply(a, d)),
+            Connective::Or => degrees.fold(F::ZERO, |a, d| self.or_method.apply(a, d)),
+        };
+        fire * rule.weight
+    }
+
+    fn defuzzify_mamdani(
+        &self,
+        grid: Option<&FixedGrid<O, T, N, F>>,
+        out_idx: usize,
+        fire: &[F; R],
+    ) -> F {
+        let out_var = &self.outputs[out_idx];
+
+        // Centroid of piecewise linear terms or singletons is computed exactly instead of on
+        // the grid, walking the knots in order without a buffer (see math_helpers::centroid_knots)
+        let kinds = out_var.terms.iter().flatten();
+        let by_centroid = matches!(self.defuzzifier, Defuzzifier::Centroid);
+        let implied = self.rules.iter().zip(fire).filter_map(|(rule, fire)| {
+            Some((*fire, Self::term(out_var, rule.consequent[out_idx]?)))
+        });
+        if by_centroid && are_singletons(kinds.clone()) {
+            if let Some(centroid) = singleton_centroid(implied, self.implication, self.aggregation)
+            {
+                return centroid;
+            }
+        } else if by_centroid && is_piecewise_linear(kinds.clone(), self.aggregation) {
+            let Range { min, max } = out_var.range;
+            let next_knot = |x: F| {
+                kinds
+                    .clone()
+                    .filter_map(MembershipKind::breakpoints)
+                    .flatten()
+                    .filter(|b| *b > x && *b < max)
+                    .fold(max, F::min)
+            };
+            let knots = core::iter::successors(Some(min), |x| (*x < max).then(|| next_knot(*x)));
+            if let Some(centroid) = piecewise_linear_centroid(
+                knots,
+                implied,
+                self.implication,
+                self.aggregation,
+                &mut [[F::ZERO; 3]; R],
+                &mut [F::ZERO; R],
+            ) {
+                return centroid;
+            }
+        }
+
+        let sampled;
+        let xs = match grid {
+            Some(grid) => &grid.xs[out_idx],
+            None => {
+                sampled = grid_points(&out_var.range);
+                &sampled
+            }
+        };
+
+        let mut agg = [F::ZERO; N];
+        let mut peak_num = F::ZERO;
+        let mut peak_den = F::ZERO;
+        for (rule, fire) in self.rules.iter().zip(fire) {
+            let Some(term) = rule.consequent[out_idx] else {
+                continue;
+            };
+            let kind = Self::term(out_var, term);
+            if let Some(peak) = kind.peak() {
+                peak_num += *fire * peak;
+                peak_den += *fire;
+            }
+            for (i, (a, x)) in agg.iter_mut().zip(xs).enumerate() {
+                let mu = match grid {
+                    Some(grid) => grid.curves[out_idx][term][i],
+                    None => kind.degree(*x),
+                };
+                *a = self
+                    .aggregation
+                    .apply(*a, self.implication.apply(*fire, mu));
+            }
+        }
+
+        self.aggregation.normalize(&mut agg);
+
+        match self.defuzzifier {
+            Defuzzifier::WeightedAverage if peak_den > F::ZERO => peak_num / peak_den,
+            ref other => other.defuzzify(xs, &agg),
+        }
+    }
+
+    fn weighted_average(
+        &self,
+        fis_type: FisType,
+        out_idx: usize,
+        fire: &[F; R],
+        crisp_inputs: &[F; I],
+    ) -> Result<F, FixedFisError> {
+        let out_var = &self.outputs[out_idx];
+        let mut num = F::ZERO;
+        let mut den = F::ZERO;
+
+        for (rule, fire) in self.rules.iter().zip(fire) {
+            let Some(term) = rule.consequent[out_idx] else {
+                continue;
+            };
+            let kind = Self::term(out_var, term);
+            let z = if fis_type == FisType::Tsukamoto {
+                kind.inverse(*fire).ok_or(FixedFisError::NonMonotonicTerm {
+                    output: out_idx,
+                    term,
+                })?
+            } else {
+                kind.evaluate(crisp_inputs)
+                    .ok_or(FixedFisError::InvalidSugenoTerm {
+                        output: out_idx,
+                        term,
+                    })?
+            };
+            num += *fire * z;
+            den += *fire;
+        }
+
+        // No rule fired: fall back to the middle of the output range
+        if den == F::ZERO {
+            Ok((out_var.range.min + out_var.range.max) / F::TWO)
+        } else {
+            Ok(num / den)
+        }
+    }
+}
+
+// same points as math_helpers::linspace
+fn grid_points<const N: usize, F: Float>(range: &Range<F>) -> [F; N] {
+    let mut xs = [range.min; N];
+    if N > 1 {
+        let step = (range.max - range.min) / F::from_usize(N - 1);
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = range.min + step * F::from_usize(i);
+        }
+    }
+    xs
+}
@@ -0,0 +1,254 @@
+/*
+Q16.16 fixed-point numbers for targets without an FPU.
+
+`Q16` implements `Float`, so any model runs on integer arithmetic only by picking
+it as the float type, e.g. the heap-free engine on an 8/16-bit MCU:
+
+    static BURNER: FixedFis<1, 1, 3, 3, 200, Q16> = FixedFis::new(...);
+
+    let [power] = BURNER.compute(FisType::Mamdani, &[Q16::from_bits(adc_reading)])?;
+
+A value is an `i32` holding x · 2^16:
+
+    range:       [-32767.99998, 32767.99998]
+    resolution:  2^-16 ≈ 0.0000153
+
+Arithmetic saturates instead of wrapping (the extremes stand in for ±infinity),
+division by zero saturates as well. A sampled centroid (`Gauss` consequents)
+accumulates Σ x·μ over the output grid, so resolution × |output range bound| should
+stay below 32767 (e.g. 200 points over 0..100).
+
+Parameters written as `f64` (`Q16::from_f64`, in a `const` or `static`) are converted
+at compile time, `exp`, `ln` and `powf` (Gauss terms, sigmoid inverses, Yager
+operators) are computed with integer series. `FixedFis` and `CompiledSystem` evaluate without any float
+conversion, while `FuzzyInferenceSystem::compute` converts the `f64` rule weights
+on every call: compile the system first on targets without an FPU.
+*/
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub};
+
+use crate::float::Float;
+
+const FRAC_BITS: u32 = 16;
+// ln 2 · 2^32
+const LN_2: i64 = 2_977_044_472;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Q16(i32);
+
+impl Q16 {
+    pub const MAX: Q16 = Q16(i32::MAX);
+    // symmetric to MAX, so negation never overflows
+    pub const MIN: Q16 = Q16(-i32::MAX);
+
+    pub const fn from_bits(bits: i32) -> Self {
+        Q16(bits)
+    }
+
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    // Rounds to the nearest step, saturates out of range values, NaN becomes zero
+    pub const fn from_f64(x: f64) -> Self {
+        let scaled = x * (1u32 << FRAC_BITS) as f64;
+        let rounded = if scaled >= 0.0 {
+            scaled + 0.5
+        } else {
+            scaled - 0.5
+        };
+        Self::saturate(rounded as i64)
+    }
+
+    const fn saturate(raw: i64) -> Self {
+        if raw > i32::MAX as i64 {
+            Self::MAX
+        } else if raw < -(i32::MAX as i64) {
+            Self::MIN
+        } else {
+            Q16(raw as i32)
+        }
+    }
+
+    // e^x for x in Q16 held in an i64, via e^x = 2^k · e^r with x = k·ln2 + r, 0 <= r < ln2
+    fn exp_raw(x: i64) -> Self {
+        let x32 = x << FRAC_BITS;
+        let k = x32.div_euclid(LN_2);
+        let r = x32.rem_euclid(LN_2) as u64;
+
+        // Taylor series of e^r in Q32, r < 0.7 so 12 terms are below the resolution
+        let mut term: u64 = 1 << 32;
+        let mut sum = term;
+        for n in 1..12u64 {
+            term = ((term * r) >> 32) / n;
+            sum += term;
+        }
+
+        // Q32 to Q16 and the 2^k scaling in one shift
+        let shift = 16 - k;
+        if shift >= 64 {
+            Q16(0)
+        } else if shift > 0 {
+            Self::saturate(((sum + (1 << (shift - 1))) >> shift) as i64)
+        } else if shift > -31 {
+            Self::saturate((sum << -shift).min(i64::MAX as u64) as i64)
+        } else {
+            Self::MAX
+        }
+    }
+
+    // ln x in Q32 for x > 0, via x = 2^k · m with 1 <= m < 2 and ln m = 2 · atanh((m - 1) / (m + 1))
+    fn ln_raw(self) -> i64 {
+        let bits = self.0 as u64;
+        let top = 63 - bits.leading_zeros() as i64;
+        let k = top - FRAC_BITS as i64;
+        let m = if top <= 32 {
+            bits << (32 - top)
+        } else {
+            bits >> (top - 32)
+        };
+
+        let one: u64 = 1 << 32;
+        let s = ((m - one) << 32) / (m + one);
+        let s2 = (s * s) >> 32;
+        let mut power = s;
+        let mut series = s;
+        for n in (3..24u64).step_by(2) {
+            power = (power * s2) >> 32;
+            series += power / n;
+        }
+        k * LN_2 + 2 * series as i64
+    }
+}
+
+impl Float for Q16 {
+    const ZERO: Self = Q16(0);
+    const ONE: Self = Q16(1 << FRAC_BITS);
+    const TWO: Self = Q16(2 << FRAC_BITS);
+    const HALF: Self = Q16(1 << (FRAC_BITS - 1));
+    const EPSILON: Self = Q16(1);
+    const INFINITY: Self = Self::MAX;
+    const NEG_INFINITY: Self = Self::MIN;
+
+    fn from_f64(x: f64) -> Self {
+        Q16::from_f64(x)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u32 << FRAC_BITS) as f64
+    }
+
+    fn from_usize(n: usize) -> Self {
+        Self::saturate((n.min(i32::MAX as usize) as i64) << FRAC_BITS)
+    }
+
+    fn to_usize(self) -> usize {
+        (self.0.max(0) >> FRAC_BITS) as usize
+    }
+
+    fn exp(self) -> Self {
+        Self::exp_raw(self.0 as i64)
+    }
+
+    // Not positive values give the lowest value (there is no NaN)
+    fn ln(self) -> Self {
+        if self.0 <= 0 {
+            return Self::MIN;
+        }
+        Self::saturate((self.ln_raw() + (1 << (FRAC_BITS - 1))) >> FRAC_BITS)
+    }
+
+    // Defined for x >= 0, a negative base gives zero (there is no NaN)
+    fn powf(self, n: Self) -> Self {
+        if n.0 == 0 {
+            return Self::ONE;
+        }
+        if self.0 <= 0 {
+            return if self.0 == 0 && n.0 < 0 {
+                Self::MAX
+            } else {
+                Self::ZERO
+            };
+        }
+        // n · ln x back to Q16, clamped far beyond the range e^x can represent
+        let y = (n.0 as i128 * self.ln_raw() as i128) >> 32;
+        Self::exp_raw(y.clamp(-(64 << FRAC_BITS), 64 << FRAC_BITS) as i64)
+    }
+}
+
+impl Add for Q16 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::saturate(self.0 as i64 + other.0 as i64)
+    }
+}
+
+impl Sub for Q16 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::saturate(self.0 as i64 - other.0 as i64)
+    }
+}
+
+impl Mul for Q16 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let product = self.0 as i64 * other.0 as i64;
+        Self::saturate((product + (1 << (FRAC_BITS - 1))) >> FRAC_BITS)
+    }
+}
+
+impl Div for Q16 {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        if other.0 == 0 {
+            return match self.0 {
+                0 => Self::ZERO,
+                x if x > 0 => Self::MAX,
+                _ => Self::MIN,
+            };
+        }
+        let num = (self.0 as i64) << FRAC_BITS;
+        let den = other.0 as i64;
+        let (quotient, remainder) = (num / den, num % den);
+        // round half away from zero
+        if 2 * remainder.abs() >= den.abs() {
+            Self::saturate(quotient + if (num < 0) == (den < 0) { 1 } else { -1 })
+        } else {
+            Self::saturate(quotient)
+        }
+    }
+}
+
+impl Neg for Q16 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Q16(self.0.saturating_neg())
+    }
+}
+
+impl AddAssign for Q16 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl DivAssign for Q16 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+// Printed as the decimal value it stands for, like f32 / f64
+impl fmt::Display for Q16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Debug for Q16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,169 @@
+/*
+Numeric type the engine computes in.
+
+Every model type takes a `F: Float` parameter defaulting to `f64`, so existing
+code keeps working while single precision targets pick `f32`:
+
+    let mut fis = FuzzyInferenceSystem::<f32>::new("heater");
+    fis.add_input(LinguisticVariable::new("temperature", Range { min: 0.0, max: 40.0 }));
+    let outputs: Vec<f32> = fis.compute(FisType::Mamdani, &[21.5])?;
+
+Operator parameters are given in `F`. Text formats and the rule weights of the
+`FuzzyInferenceSystem` model stay `f64`, `compile()` and the fixed-capacity engine
+convert the weights once, ahead of any evaluation.
+*/
+
+use core::fmt::{Debug, Display};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub};
+
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Default
+    + Debug
+    + Display
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const HALF: Self;
+    // machine epsilon, the tolerance of exact comparisons in membership functions
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f64(x: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn exp(self) -> Self;
+    // natural logarithm, negative infinity at zero
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+
+    fn from_usize(n: usize) -> Self {
+        Self::from_f64(n as f64)
+    }
+
+    // integer part of a non-negative value, e.g. an index into sampled points
+    fn to_usize(self) -> usize {
+        self.to_f64() as usize
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    // NaN is ignored, as by f64::max and f64::min
+    fn max(self, other: Self) -> Self {
+        if self.is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.is_nan() || self > other {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    fn is_nan(self) -> bool {
+        self.partial_cmp(&self).is_none()
+    }
+}
+
+// exp, ln and powf live outside of `core`: std when available, libm on no_std targets
+macro_rules! impl_float {
+    ($t:ident, $exp:path, $ln:path, $pow:path) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const HALF: Self = 0.5;
+            const EPSILON: Self = $t::EPSILON;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[cfg(feature = "std")]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn exp(self) -> Self {
+                $exp(self)
+            }
+
+            #[cfg(feature = "std")]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn ln(self) -> Self {
+                $ln(self)
+            }
+
+            #[cfg(feature = "std")]
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn powf(self, n: Self) -> Self {
+                $pow(self, n)
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32, libm::expf, libm::logf, libm::powf);
+impl_float!(f64, libm::exp, libm::log, libm::pow);
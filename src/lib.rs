@@ -1,7 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod compiled;
+pub mod defuzzifier;
+#[cfg(feature = "alloc")]
+pub mod fcl;
 pub mod fis;
+#[cfg(feature = "alloc")]
+pub mod fis_format;
+pub mod fixed;
+pub mod fixed_point;
+pub mod float;
 pub mod math_helpers;
 pub mod membership;
+pub mod operators;
+#[cfg(feature = "alloc")]
 pub mod output;
+#[cfg(feature = "alloc")]
+pub mod parser;
 pub mod rule;
+#[cfg(feature = "alloc")]
 pub mod term;
+#[cfg(feature = "alloc")]
+pub mod validation;
 pub mod variable;
@@ -1,3 +1,15 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use crate::variable::Range;
+use crate::{
+    float::Float,
+    membership::MembershipKind,
+    operators::{Aggregation, Implication},
+};
+
 /*
 It’s basically chopping up the interval [min, max] into evenly spaced points.
 
@@ -18,12 +30,13 @@ linspace function is just:
 - Divide it into n-1 equal segments.
 - Mark each division point, including the ends.
 */
-pub fn linspace(min: f64, max: f64, n: usize) -> Vec<f64> {
+#[cfg(feature = "alloc")]
+pub fn linspace<F: Float>(min: F, max: F, n: usize) -> Vec<F> {
     if n <= 1 {
         return vec![min];
     }
-    let step = (max - min) / (n as f64 - 1.0);
-    (0..n).map(|i| min + step * i as f64).collect()
+    let step = (max - min) / F::from_usize(n - 1);
+    (0..n).map(|i| min + step * F::from_usize(i)).collect()
 }
 
 /*
@@ -46,16 +59,330 @@ Imagine a fuzzy set shaped like a triangle:
 - If the fuzzy set is symmetric, the centroid is right in the middle.
 - If the set is skewed, the centroid shifts toward the heavier side.
 */
-pub fn centroid(xs: &[f64], mus: &[f64]) -> f64 {
-    let mut num = 0.0;
-    let mut den = 0.0;
+pub fn centroid<F: Float>(xs: &[F], mus: &[F]) -> F {
+    let mut num = F::ZERO;
+    let mut den = F::ZERO;
     for (x, mu) in xs.iter().zip(mus.iter()) {
-        num += x * mu;
-        den += mu;
+        num += *x * *mu;
+        den += *mu;
     }
-    if den == 0.0 {
-        xs.get(xs.len() / 2).copied().unwrap_or(0.0)
+    if den == F::ZERO {
+        xs.get(xs.len() / 2).copied().unwrap_or(F::ZERO)
     } else {
         num / den
     }
 }
+
+/*
+Visualizing the bisector
+
+ μ(x)
+ 1.0 |        ______
+     |       /   |  \
+     |      /    |   \
+ 0.0 |-----/-----B----\---------
+           |  A  |  A  |
+             bisector
+
+- The vertical line at B splits the area under the fuzzy set into two equal halves A.
+- For symmetric sets bisector and centroid coincide.
+*/
+pub fn bisector<F: Float>(xs: &[F], mus: &[F]) -> F {
+    let total = mus.iter().fold(F::ZERO, |acc, mu| acc + *mu);
+    if total == F::ZERO {
+        return xs.get(xs.len() / 2).copied().unwrap_or(F::ZERO);
+    }
+    let half = total / F::TWO;
+    let mut acc = F::ZERO;
+    for (x, mu) in xs.iter().zip(mus.iter()) {
+        acc += *mu;
+        if acc >= half {
+            return *x;
+        }
+    }
+    xs.last().copied().unwrap_or(F::ZERO)
+}
+
+/*
+Visualizing the maximum based methods
+
+ μ(x)
+ 1.0 |      __________
+     |     /          \
+     |    /            \
+ 0.0 |---/--------------\-------
+         S      M       L
+
+- S: smallest of maximum, the first x reaching the highest degree
+- L: largest of maximum, the last x reaching the highest degree
+- M: mean of maximum, the average of all x reaching the highest degree
+*/
+fn maximum_points<'a, F: Float>(
+    xs: &'a [F],
+    mus: &'a [F],
+) -> impl DoubleEndedIterator<Item = F> + 'a {
+    let max = mus.iter().copied().fold(F::ZERO, F::max);
+    // ties up to rounding noise, a few steps of the float type's precision
+    let tolerance = F::EPSILON * F::from_usize(16);
+    xs.iter()
+        .zip(mus.iter())
+        .filter(move |(_, mu)| max > F::ZERO && (max - **mu).abs() < tolerance)
+        .map(|(x, _)| *x)
+}
+
+pub fn mean_of_maximum<F: Float>(xs: &[F], mus: &[F]) -> F {
+    let (sum, count) =
+        maximum_points(xs, mus).fold((F::ZERO, 0), |(sum, count), x| (sum + x, count + 1));
+    if count == 0 {
+        return xs.get(xs.len() / 2).copied().unwrap_or(F::ZERO);
+    }
+    sum / F::from_usize(count)
+}
+
+pub fn smallest_of_maximum<F: Float>(xs: &[F], mus: &[F]) -> F {
+    match maximum_points(xs, mus).next() {
+        Some(x) => x,
+        None => xs.get(xs.len() / 2).copied().unwrap_or(F::ZERO),
+    }
+}
+
+pub fn largest_of_maximum<F: Float>(xs: &[F], mus: &[F]) -> F {
+    match maximum_points(xs, mus).next_back() {
+        Some(x) => x,
+        None => xs.get(xs.len() / 2).copied().unwrap_or(F::ZERO),
+    }
+}
+
+/*
+Exact centroid of a Mamdani aggregated set built from piecewise linear terms
+
+ μ(x)
+ 1.0 |       /\
+ fire|------*--*-----________
+     |     /    \   /        \
+ 0.0 |----/------\-/----------\---
+          k  c   c k   c      k
+
+- k: knots, the breakpoints of the consequent terms (plus the range ends)
+- c: cuts, where a term crosses the clipping level of its rule (or where implied
+  curves overtake each other under max aggregation)
+- between consecutive knots and cuts the aggregated set is a straight line, so its
+  area and first moment are summed segment by segment without sampling.
+*/
+
+// Whether the aggregated set of these consequent kinds is piecewise linear: no curved
+// term (Gauss) and no probabilistic sum (acc + mu - acc * mu)
+pub fn is_piecewise_linear<'a, F: Float>(
+    kinds: impl IntoIterator<Item = &'a MembershipKind<F>>,
+    aggregation: Aggregation,
+) -> bool {
+    aggregation != Aggregation::ProbabilisticSum
+        && kinds.into_iter().all(|kind| kind.breakpoints().is_some())
+}
+
+// Sorted knots of `kinds` within `range`, including its ends
+#[cfg(feature = "alloc")]
+pub fn centroid_knots<'a, F: Float>(
+    range: Range<F>,
+    kinds: impl IntoIterator<Item = &'a MembershipKind<F>>,
+) -> Vec<F> {
+    if range.max <= range.min {
+        return vec![range.min];
+    }
+    let mut knots = vec![range.min, range.max];
+    for kind in kinds {
+        if let Some(points) = kind.breakpoints() {
+            knots.extend(points.filter(|x| *x > range.min && *x < range.max));
+        }
+    }
+    knots.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    knots.dedup();
+    knots
+}
+
+// Centroid of the rules' implied consequents, given as (firing strength, term) pairs, over
+// sorted `knots`. `lines` and `cuts` are scratch buffers with room for every pair, so no
+// allocation happens here. None when nothing fired.
+pub fn piecewise_linear_centroid<'a, F: Float>(
+    knots: impl IntoIterator<Item = F>,
+    implied: impl Iterator<Item = (F, &'a MembershipKind<F>)> + Clone,
+    implication: Implication,
+    aggregation: Aggregation,
+    lines: &mut [[F; 3]],
+    cuts: &mut [F],
+) -> Option<F> {
+    let three = F::TWO + F::ONE;
+    let mut knots = knots.into_iter();
+    // moments are taken around the first knot, which keeps the sums small (fixed-point types)
+    let origin = knots.next()?;
+    let mut p = origin;
+    let mut area = F::ZERO;
+    let mut moment = F::ZERO;
+
+    for q in knots {
+        let (start, h) = (p, q - p);
+        // segment start relative to the origin
+        let x = start - origin;
+        p = q;
+
+        // Every term is a line between two knots: (fire, μ(p), μ(q)) per rule, taken from
+        // inner points so that a vertical edge at a knot does not matter
+        let mut count = 0;
+        for (fire, kind) in implied.clone().filter(|(fire, _)| *fire > F::ZERO) {
+            let m1 = kind.degree(start + h / three);
+            let m2 = kind.degree(start + h * F::TWO / three);
+            lines[count] = [fire, m1 + m1 - m2, m2 + m2 - m1];
+            count += 1;
+        }
+        let lines = &lines[..count];
+        if lines.is_empty() {
+            continue;
+        }
+
+        // Cuts in [0, 1] along the segment, where the implication bends a line
+        let mut cut_count = 0;
+        for [fire, y0, y1] in lines.iter().copied() {
+            let level = match implication {
+                Implication::Min => fire,
+                Implication::Lukasiewicz => F::ONE - fire,
+                Implication::Product => continue,
+            };
+            if (y0 < level) != (y1 < level) {
+                cuts[cut_count] = (level - y0) / (y1 - y0);
+                cut_count += 1;
+            }
+        }
+        let cuts = &mut cuts[..cut_count];
+        cuts.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        // Implied degree of line i at position u along the segment
+        let implied_at = |i: usize, u: F| {
+            let [fire, y0, y1] = lines[i];
+            implication.apply(fire, y0 + (y1 - y0) * u)
+        };
+        let mut add = |u0: F, u1: F, mu0: F, mu1: F| {
+            let (x0, x1) = (x + h * u0, x + h * u1);
+            let dx = x1 - x0;
+            area += dx * (mu0 + mu1) / F::TWO;
+            moment += dx / (F::TWO * three) * (x0 * (mu0 + mu0 + mu1) + x1 * (mu0 + mu1 + mu1));
+        };
+
+        let mut u0 = F::ZERO;
+        for u1 in cuts.iter().copied().chain([F::ONE]) {
+            if u1 <= u0 {
+                continue;
+            }
+            match aggregation {
+                // upper envelope of the lines: from the top line, switch to the first one
+                // overtaking it; every switch raises the end value, so it takes at most one
+                // piece per line (a crossing rounded onto u switches at once)
+                Aggregation::Max => {
+                    let mut u = u0;
+                    let mut top = (1..lines.len()).fold(0, |top, i| {
+                        if implied_at(i, u) > implied_at(top, u) {
+                            i
+                        } else {
+                            top
+                        }
+                    });
+                    loop {
+                        let mut next = (u1, None);
+                        let mut i = 0;
+                        while i < lines.len() {
+                            let gap1 = implied_at(i, u1) - implied_at(top, u1);
+                            if gap1 > F::ZERO {
+                                let gap0 = implied_at(top, u) - implied_at(i, u);
+                                let t = u + (u1 - u) * gap0 / (gap0 + gap1);
+                                if t <= u {
+                                    (top, next, i) = (i, (u1, None), 0);
+                                    continue;
+                                }
+                                if t < next.0 {
+                                    next = (t, Some(i));
+                                }
+                            }
+                            i += 1;
+                        }
+                        add(u, next.0, implied_at(top, u), implied_at(top, next.0));
+                        match next {
+                            (t, Some(i)) => (u, top) = (t, i),
+                            (_, None) => break,
+                        }
+                    }
+                }
+                // a sum of lines, capped at 1 by the bounded sum; NormalizedSum scales the
+                // whole set, which leaves the centroid unchanged
+                _ => {
+                    let sum_at =
+                        |u: F| (0..lines.len()).fold(F::ZERO, |acc, i| acc + implied_at(i, u));
+                    let (s0, s1) = (sum_at(u0), sum_at(u1));
+                    if aggregation == Aggregation::BoundedSum && (s0 > F::ONE) != (s1 > F::ONE) {
+                        let u = u0 + (u1 - u0) * (F::ONE - s0) / (s1 - s0);
+                        add(u0, u, s0.min(F::ONE), F::ONE);
+                        add(u, u1, F::ONE, s1.min(F::ONE));
+                    } else if aggregation == Aggregation::BoundedSum {
+                        add(u0, u1, s0.min(F::ONE), s1.min(F::ONE));
+                    } else {
+                        add(u0, u1, s0, s1);
+                    }
+                }
+            }
+            u0 = u1;
+        }
+    }
+
+    if area > F::ZERO {
+        Some(origin + moment / area)
+    } else {
+        None
+    }
+}
+
+/*
+Singleton consequents are spikes of zero width, which a sampled grid either misses or
+hits depending on the resolution. The centroid of their aggregated set is a weighted
+average instead: rules concluding the same point are aggregated into its height h, then
+
+    z = Σ h_k · x_k / Σ h_k
+*/
+
+// Whether every consequent kind is a crisp point (a Singleton, or a Sugeno Constant)
+pub fn are_singletons<'a, F: Float>(
+    kinds: impl IntoIterator<Item = &'a MembershipKind<F>>,
+) -> bool {
+    kinds.into_iter().all(|kind| {
+        matches!(
+            kind,
+            MembershipKind::Singleton { .. } | MembershipKind::Constant { .. }
+        )
+    })
+}
+
+// Centroid of the rules' implied singletons, given as (firing strength, term) pairs,
+// without allocation. None when nothing fired.
+pub fn singleton_centroid<'a, F: Float>(
+    implied: impl Iterator<Item = (F, &'a MembershipKind<F>)> + Clone,
+    implication: Implication,
+    aggregation: Aggregation,
+) -> Option<F> {
+    let mut num = F::ZERO;
+    let mut den = F::ZERO;
+    for (i, (_, kind)) in implied.clone().enumerate() {
+        let x = kind.peak();
+        // every point once, at its first rule
+        if implied.clone().take(i).any(|(_, other)| other.peak() == x) {
+            continue;
+        }
+        let height = implied
+            .clone()
+            .filter(|(_, other)| other.peak() == x)
+            .fold(F::ZERO, |acc, (fire, _)| {
+                aggregation.apply(acc, implication.apply(fire, F::ONE))
+            });
+        if let Some(x) = x {
+            num += height * x;
+            den += height;
+        }
+    }
+    (den > F::ZERO).then(|| num / den)
+}
@@ -1,12 +1,177 @@
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt::Debug;
+
+use crate::float::Float;
+use crate::variable::Range;
+
+/*
+User-defined membership function, plugged into a term through `MembershipKind::Custom`
+without extending the enum, e.g. a logistic dose-response curve:
+
+    #[derive(Debug)]
+    struct DoseResponse { ec50: f64, hill: f64 }
+
+    impl Membership for DoseResponse {
+        fn degree(&self, dose: f64) -> f64 {
+            1.0 / (1.0 + (self.ec50 / dose).powf(self.hill))
+        }
+        fn support(&self) -> Range { Range { min: 0.0, max: f64::INFINITY } }
+        fn core(&self) -> Range { Range { min: 10.0 * self.ec50, max: f64::INFINITY } }
+    }
+
+    Term::new("effective", MembershipKind::custom(DoseResponse { ec50: 2.0, hill: 3.0 }))
+
+A custom term has no inverse, so it cannot be a Tsukamoto consequent
+(`FisError::NonMonotonicTerm`).
+*/
+pub trait Membership<F: Float = f64>: Debug + Send + Sync {
+    fn degree(&self, x: F) -> F;
+
+    // interval where the degree is above zero, bounds may be infinite
+    fn support(&self) -> Range<F>;
+
+    // interval where the degree is one (or highest), its center (or finite end) is the term peak
+    fn core(&self) -> Range<F>;
+
+    // slope of the degree at x, None when not known; for callers only, the
+    // engine itself never differentiates terms
+    fn derivative(&self, _x: F) -> Option<F> {
+        None
+    }
+}
+
+// Shared object with an allocator, a static one without one
+#[cfg(feature = "alloc")]
+pub type CustomMembership<F> = Arc<dyn Membership<F>>;
+#[cfg(not(feature = "alloc"))]
+pub type CustomMembership<F> = &'static dyn Membership<F>;
+
 #[derive(Debug, Clone)]
-pub enum MembershipKind {
-    Triangle { a: f64, b: f64, c: f64 },
-    Trapezoid { a: f64, b: f64, c: f64, d: f64 },
-    Gauss { sigma: f64, mu: f64 },
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum MembershipKind<F: Float = f64> {
+    Triangle {
+        a: F,
+        b: F,
+        c: F,
+    },
+    Trapezoid {
+        a: F,
+        b: F,
+        c: F,
+        d: F,
+    },
+    // Open-ended ramps for the ends of a universe: a left shoulder is fully true up to a
+    // and falls to 0 at b, a right shoulder rises from 0 at a and is fully true from b on
+    LeftShoulder {
+        a: F,
+        b: F,
+    },
+    RightShoulder {
+        a: F,
+        b: F,
+    },
+    // Arbitrary shape through (x, μ) points in ascending x, linearly interpolated and
+    // holding the first / last μ outside; equal x values make a vertical edge
+    #[cfg(feature = "alloc")]
+    Polygon {
+        points: Vec<(F, F)>,
+    },
+    // Tabulated μ values evenly spaced from min to max (both included), e.g. a measured
+    // histogram, linearly interpolated and holding the first / last value outside
+    #[cfg(feature = "alloc")]
+    Sampled {
+        min: F,
+        max: F,
+        values: Vec<F>,
+    },
+    Gauss {
+        sigma: F,
+        mu: F,
+    },
+    // Generalized bell: 1 / (1 + |(x - c) / a|^(2b)), a = half width, b = steepness
+    Bell {
+        a: F,
+        b: F,
+        c: F,
+    },
+    // 1 / (1 + e^(-a (x - c))), opening right for a > 0 and left for a < 0
+    Sigmoid {
+        a: F,
+        c: F,
+    },
+    // Difference of two sigmoids (the first minus the second), a soft plateau
+    DiffSigmoid {
+        a1: F,
+        c1: F,
+        a2: F,
+        c2: F,
+    },
+    // Product of two sigmoids, a soft plateau
+    ProdSigmoid {
+        a1: F,
+        c1: F,
+        a2: F,
+        c2: F,
+    },
+    // Spline-based shapes: S rises from 0 at a to 1 at b, Z falls from 1 at a to 0 at b,
+    // Pi is S over [a, b] times Z over [c, d]
+    SShape {
+        a: F,
+        b: F,
+    },
+    ZShape {
+        a: F,
+        b: F,
+    },
+    PiShape {
+        a: F,
+        b: F,
+        c: F,
+        d: F,
+    },
+    // Two-sided Gaussian: the left half of (sigma1, mu1), 1 between mu1 and mu2,
+    // the right half of (sigma2, mu2)
+    Gauss2 {
+        sigma1: F,
+        mu1: F,
+        sigma2: F,
+        mu2: F,
+    },
+    // Crisp Mamdani consequent: fully true at value only, defuzzified without sampling
+    Singleton {
+        value: F,
+    },
+    // Sugeno consequents: a crisp constant (zero-order) or a linear
+    // function of the crisp inputs (first-order): z = c0 + c1*x1 + ... + cn*xn
+    Constant {
+        value: F,
+    },
+    #[cfg(feature = "alloc")]
+    Linear {
+        coefficients: Vec<F>,
+        constant: F,
+    },
+    // trait objects cannot be serialized, systems using one fail to serialize
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(CustomMembership<F>),
 }
 
-impl MembershipKind {
-    pub fn degree(&self, x: f64) -> f64 {
+impl<F: Float> MembershipKind<F> {
+    #[cfg(feature = "alloc")]
+    pub fn custom(membership: impl Membership<F> + 'static) -> Self {
+        MembershipKind::Custom(Arc::new(membership))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    pub const fn custom(membership: &'static dyn Membership<F>) -> Self {
+        MembershipKind::Custom(membership)
+    }
+
+    // the custom object is a plain reference without an allocator
+    #[cfg_attr(not(feature = "alloc"), allow(clippy::needless_borrow))]
+    pub fn degree(&self, x: F) -> F {
         match *self {
             /*
              μ(x)
@@ -19,11 +184,12 @@ impl MembershipKind {
              0.0 |------/----------\--------->
                     a   b          c          x
             */
+            // the peak is checked first, so a vertical edge (a == b or b == c) is fully true
             MembershipKind::Triangle { a, b, c } => {
-                if x <= a || x >= c {
-                    0.0
-                } else if (x - b).abs() < f64::EPSILON {
-                    1.0
+                if (x - b).abs() < F::EPSILON {
+                    F::ONE
+                } else if x <= a || x >= c {
+                    F::ZERO
                 } else if x < b {
                     (x - a) / (b - a)
                 } else {
@@ -42,16 +208,80 @@ impl MembershipKind {
                    a   b                 c    d     x
             */
             MembershipKind::Trapezoid { a, b, c, d } => {
-                if x <= a || x >= d {
-                    0.0
-                } else if x >= b && x <= c {
-                    1.0
-                } else if x > a && x < b {
+                if x >= b && x <= c {
+                    F::ONE
+                } else if x <= a || x >= d {
+                    F::ZERO
+                } else if x < b {
                     (x - a) / (b - a)
                 } else {
                     (d - x) / (d - c)
                 }
             }
+            /*
+             μ(x)
+                 ^
+             1.0 |________                      ________
+                 |        \                    /
+                 |         \                  /
+             0.5 |          \   L        R   /
+                 |           \              /
+             0.0 |------------\------------/----------->
+                          a    b          a   b            x
+            */
+            MembershipKind::LeftShoulder { a, b } => {
+                if x <= a {
+                    F::ONE
+                } else if x >= b {
+                    F::ZERO
+                } else {
+                    (b - x) / (b - a)
+                }
+            }
+            MembershipKind::RightShoulder { a, b } => {
+                if x >= b {
+                    F::ONE
+                } else if x <= a {
+                    F::ZERO
+                } else {
+                    (x - a) / (b - a)
+                }
+            }
+            /*
+             μ(x)
+                 ^
+             1.0 |          *---*
+                 |         /     \       *--------
+             0.5 |        /       *     /
+                 |       /         \   /
+             0.0 |------*           *-*---------->
+                        p0  p1  p2  p3 p4 p5        x
+            */
+            #[cfg(feature = "alloc")]
+            MembershipKind::Polygon { ref points } => {
+                // first point right of x, so at a vertical edge the last point wins
+                let i = points.partition_point(|(px, _)| *px <= x);
+                match (i.checked_sub(1).map(|j| points[j]), points.get(i)) {
+                    (Some((x0, y0)), Some(&(x1, y1))) => y0 + (y1 - y0) * (x - x0) / (x1 - x0),
+                    (Some((_, y)), None) | (None, Some(&(_, y))) => y,
+                    (None, None) => F::ZERO,
+                }
+            }
+            #[cfg(feature = "alloc")]
+            MembershipKind::Sampled {
+                min,
+                max,
+                ref values,
+            } => match values.len() {
+                0 => F::ZERO,
+                1 => values[0],
+                n => {
+                    let last = F::from_usize(n - 1);
+                    let t = ((x - min) / (max - min) * last).clamp(F::ZERO, last);
+                    let i = t.to_usize().min(n - 2);
+                    values[i] + (values[i + 1] - values[i]) * (t - F::from_usize(i))
+                }
+            },
             /*
              μ(x)
                  ^
@@ -68,10 +298,261 @@ impl MembershipKind {
              - σ (sigma) controls the width (spread)
              - μ (mu) is the center of the Gaussian (the peak)
             */
-            MembershipKind::Gauss { sigma, mu } => {
-                let t = (x - mu) / sigma;
-                (-0.5 * t * t).exp()
+            MembershipKind::Gauss { sigma, mu } => gauss(sigma, mu, x),
+            /*
+             μ(x)
+                 ^
+             1.0 |          ___
+                 |        /     \
+             0.5 |       *       *
+                 |     /           \
+             0.0 |___--             --___--->
+                        c-a   c   c+a          x
+            */
+            MembershipKind::Bell { a, b, c } => F::ONE / (F::ONE + ((x - c) / a).abs().powf(b + b)),
+            /*
+             μ(x)
+                 ^
+             1.0 |                   ______
+                 |                 /
+             0.5 |----------------*
+                 |               /
+             0.0 |______________/---------->
+                                c           x
+            */
+            MembershipKind::Sigmoid { a, c } => sigmoid(a, c, x),
+            MembershipKind::DiffSigmoid { a1, c1, a2, c2 } => {
+                (sigmoid(a1, c1, x) - sigmoid(a2, c2, x)).clamp(F::ZERO, F::ONE)
             }
+            MembershipKind::ProdSigmoid { a1, c1, a2, c2 } => {
+                sigmoid(a1, c1, x) * sigmoid(a2, c2, x)
+            }
+            /*
+             μ(x)
+                 ^
+             1.0 |        ____            ____
+                 |      /                     \
+             0.5 |     *         S    Z        *
+                 |    /                         \
+             0.0 |___/                           \___--->
+                    a   b                       a   b     x
+            */
+            MembershipKind::SShape { a, b } => s_shape(a, b, x),
+            MembershipKind::ZShape { a, b } => F::ONE - s_shape(a, b, x),
+            MembershipKind::PiShape { a, b, c, d } => {
+                s_shape(a, b, x) * (F::ONE - s_shape(c, d, x))
+            }
+            MembershipKind::Gauss2 {
+                sigma1,
+                mu1,
+                sigma2,
+                mu2,
+            } => {
+                let left = if x < mu1 {
+                    gauss(sigma1, mu1, x)
+                } else {
+                    F::ONE
+                };
+                let right = if x > mu2 {
+                    gauss(sigma2, mu2, x)
+                } else {
+                    F::ONE
+                };
+                left * right
+            }
+            // A constant consequent behaves like a singleton spike at its value
+            MembershipKind::Singleton { value } | MembershipKind::Constant { value } => {
+                if (x - value).abs() < F::EPSILON {
+                    F::ONE
+                } else {
+                    F::ZERO
+                }
+            }
+            // A linear consequent has no shape over a single output axis
+            #[cfg(feature = "alloc")]
+            MembershipKind::Linear { .. } => F::ZERO,
+            MembershipKind::Custom(ref membership) => membership.degree(x),
+        }
+    }
+
+    // Representative crisp point of the term (center of its core), used by
+    // weighted average defuzzification; None for linear Sugeno consequents
+    #[cfg_attr(not(feature = "alloc"), allow(clippy::needless_borrow))]
+    pub fn peak(&self) -> Option<F> {
+        match *self {
+            MembershipKind::Triangle { b, .. } => Some(b),
+            MembershipKind::Trapezoid { b, c, .. } => Some((b + c) / F::TWO),
+            MembershipKind::Gauss { mu, .. } => Some(mu),
+            MembershipKind::Bell { c, .. } => Some(c),
+            // open shapes: the point where the degree crosses 0.5 or reaches 1
+            MembershipKind::LeftShoulder { a, .. } => Some(a),
+            MembershipKind::RightShoulder { b, .. } => Some(b),
+            MembershipKind::Sigmoid { c, .. } => Some(c),
+            MembershipKind::DiffSigmoid { c1, c2, .. }
+            | MembershipKind::ProdSigmoid { c1, c2, .. } => Some((c1 + c2) / F::TWO),
+            MembershipKind::SShape { b, .. } => Some(b),
+            MembershipKind::ZShape { a, .. } => Some(a),
+            MembershipKind::PiShape { b, c, .. } => Some((b + c) / F::TWO),
+            MembershipKind::Gauss2 { mu1, mu2, .. } => Some((mu1 + mu2) / F::TWO),
+            #[cfg(feature = "alloc")]
+            MembershipKind::Polygon { ref points } => core_center(points.iter().copied()),
+            #[cfg(feature = "alloc")]
+            MembershipKind::Sampled { ref values, .. } => {
+                let knots = self.breakpoints()?;
+                core_center(knots.zip(values.iter().copied()))
+            }
+            MembershipKind::Singleton { value } | MembershipKind::Constant { value } => Some(value),
+            #[cfg(feature = "alloc")]
+            MembershipKind::Linear { .. } => None,
+            // the finite end of an open core
+            MembershipKind::Custom(ref membership) => match membership.core() {
+                Range { min, max } if min > F::NEG_INFINITY && max < F::INFINITY => {
+                    Some((min + max) / F::TWO)
+                }
+                Range { min, .. } if min > F::NEG_INFINITY => Some(min),
+                Range { max, .. } if max < F::INFINITY => Some(max),
+                _ => None,
+            },
+        }
+    }
+
+    // x positions where the degree changes slope; None when the shape is not piecewise
+    // linear (the degree is linear between consecutive breakpoints and constant outside)
+    pub fn breakpoints(&self) -> Option<impl Iterator<Item = F> + '_> {
+        // corner points, polygon points and (min, max, count) of evenly spaced samples
+        let none = (F::ZERO, F::ZERO, 0);
+        let ((corners, len), points, (min, max, n)) = match *self {
+            MembershipKind::Triangle { a, b, c } => (([a, b, c, c], 3), &[][..], none),
+            MembershipKind::Trapezoid { a, b, c, d } => (([a, b, c, d], 4), &[][..], none),
+            MembershipKind::LeftShoulder { a, b } | MembershipKind::RightShoulder { a, b } => {
+                (([a, b, b, b], 2), &[][..], none)
+            }
+            #[cfg(feature = "alloc")]
+            MembershipKind::Polygon { ref points } => (([F::ZERO; 4], 0), &points[..], none),
+            #[cfg(feature = "alloc")]
+            MembershipKind::Sampled {
+                min,
+                max,
+                ref values,
+            } => (([F::ZERO; 4], 0), &[][..], (min, max, values.len())),
+            _ => return None,
+        };
+        let last = F::from_usize(n.max(2) - 1);
+        Some(
+            corners
+                .into_iter()
+                .take(len)
+                .chain(points.iter().map(|&(x, _): &(F, F)| x))
+                .chain((0..n).map(move |i| min + (max - min) * F::from_usize(i) / last)),
+        )
+    }
+
+    // Sugeno output level of a consequent for the given crisp inputs,
+    // None when the kind is not a Sugeno consequent or the arity does not match
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn evaluate(&self, inputs: &[F]) -> Option<F> {
+        match self {
+            MembershipKind::Singleton { value } | MembershipKind::Constant { value } => {
+                Some(*value)
+            }
+            #[cfg(feature = "alloc")]
+            MembershipKind::Linear {
+                coefficients,
+                constant,
+            } => {
+                if coefficients.len() != inputs.len() {
+                    return None;
+                }
+                let sum = coefficients
+                    .iter()
+                    .zip(inputs.iter())
+                    .fold(F::ZERO, |acc, (c, x)| acc + *c * *x);
+                Some(*constant + sum)
+            }
+            _ => None,
+        }
+    }
+
+    /*
+    Tsukamoto consequents must be monotonic, so that a firing strength maps back
+    to a single crisp value. Supported shapes are the shoulders, the "ramp" halves
+    of Triangle (a == b falling, b == c rising) and Trapezoid (a == b falling, c == d
+    rising), Sigmoid, SShape and ZShape. A sigmoid only reaches 0 and 1 at infinity,
+    degrees closer than EPSILON to them are inverted as EPSILON and 1 - EPSILON.
+
+     μ(x)
+         ^
+     1.0 |             ______
+         |            /
+     fire|-----------*
+         |          /|
+     0.0 |---------/-+----------->
+                   a z  b         x
+    */
+    pub fn inverse(&self, mu: F) -> Option<F> {
+        let mu = mu.clamp(F::ZERO, F::ONE);
+        match *self {
+            MembershipKind::LeftShoulder { a, b } if a < b => Some(b - mu * (b - a)),
+            MembershipKind::RightShoulder { a, b } if a < b => Some(a + mu * (b - a)),
+            MembershipKind::Triangle { a, b, c } if a == b && b < c => Some(c - mu * (c - b)),
+            MembershipKind::Triangle { a, b, c } if b == c && a < b => Some(a + mu * (b - a)),
+            MembershipKind::Trapezoid { a, b, c, d } if a == b && c < d => Some(d - mu * (d - c)),
+            MembershipKind::Trapezoid { a, b, c, d } if c == d && a < b => Some(a + mu * (b - a)),
+            MembershipKind::Sigmoid { a, c } if a != F::ZERO => {
+                let mu = mu.clamp(F::EPSILON, F::ONE - F::EPSILON);
+                // ln(μ / (1 - μ)) as a difference, the ratio overflows fixed-point types
+                Some(c + (mu.ln() - (F::ONE - mu).ln()) / a)
+            }
+            MembershipKind::SShape { a, b } if a < b => Some(s_shape_inverse(a, b, mu)),
+            MembershipKind::ZShape { a, b } if a < b => Some(s_shape_inverse(a, b, F::ONE - mu)),
+            _ => None,
         }
     }
 }
+
+// Middle of the first and last (x, μ) reaching the highest μ
+#[cfg(feature = "alloc")]
+fn core_center<F: Float>(points: impl Iterator<Item = (F, F)>) -> Option<F> {
+    let mut core: Option<(F, F, F)> = None;
+    for (x, mu) in points {
+        core = match core {
+            Some((top, first, _)) if mu == top => Some((top, first, x)),
+            Some((top, ..)) if mu < top => core,
+            _ => Some((mu, x, x)),
+        };
+    }
+    core.map(|(_, first, last)| (first + last) / F::TWO)
+}
+
+fn gauss<F: Float>(sigma: F, mu: F, x: F) -> F {
+    let t = (x - mu) / sigma;
+    (-F::HALF * t * t).exp()
+}
+
+fn sigmoid<F: Float>(a: F, c: F, x: F) -> F {
+    F::ONE / (F::ONE + (-a * (x - c)).exp())
+}
+
+// Two quadratic halves meeting at the midpoint of [a, b]
+fn s_shape<F: Float>(a: F, b: F, x: F) -> F {
+    if x <= a {
+        F::ZERO
+    } else if x >= b {
+        F::ONE
+    } else if x <= (a + b) / F::TWO {
+        let t = (x - a) / (b - a);
+        F::TWO * t * t
+    } else {
+        let t = (x - b) / (b - a);
+        F::ONE - F::TWO * t * t
+    }
+}
+
+// x where s_shape reaches mu, solving the quadratic half that mu falls in
+fn s_shape_inverse<F: Float>(a: F, b: F, mu: F) -> F {
+    if mu <= F::HALF {
+        a + (b - a) * (mu / F::TWO).powf(F::HALF)
+    } else {
+        b - (b - a) * ((F::ONE - mu) / F::TWO).powf(F::HALF)
+    }
+}
@@ -0,0 +1,179 @@
+use crate::float::Float;
+
+// Triangular norms (fuzzy AND) used to combine antecedent degrees
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TNorm<F: Float = f64> {
+    // min(a, b)
+    #[default]
+    Min,
+    // a * b
+    Product,
+    // max(0, a + b - 1)
+    Lukasiewicz,
+    // b if a == 1, a if b == 1, otherwise 0
+    Drastic,
+    // a * b / (2 - (a + b - a * b))
+    Einstein,
+    // a * b / (p + (1 - p) * (a + b - a * b)), with p >= 0
+    Hamacher(F),
+}
+
+impl<F: Float> TNorm<F> {
+    pub fn apply(&self, a: F, b: F) -> F {
+        match *self {
+            TNorm::Min => a.min(b),
+            TNorm::Product => a * b,
+            TNorm::Lukasiewicz => (a + b - F::ONE).max(F::ZERO),
+            TNorm::Drastic => {
+                if a == F::ONE {
+                    b
+                } else if b == F::ONE {
+                    a
+                } else {
+                    F::ZERO
+                }
+            }
+            TNorm::Einstein => a * b / (F::TWO - (a + b - a * b)),
+            TNorm::Hamacher(p) => {
+                let den = p + (F::ONE - p) * (a + b - a * b);
+                if den == F::ZERO {
+                    F::ZERO
+                } else {
+                    a * b / den
+                }
+            }
+        }
+    }
+}
+
+// Triangular conorms (fuzzy OR) used to combine antecedent degrees
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SNorm<F: Float = f64> {
+    // max(a, b)
+    #[default]
+    Max,
+    // a + b - a * b
+    ProbabilisticSum,
+    // min(1, a + b)
+    BoundedSum,
+    // b if a == 0, a if b == 0, otherwise 1
+    Drastic,
+    // (a + b) / (1 + a * b)
+    Einstein,
+    // (a + b + (p - 2) * a * b) / (1 + (p - 1) * a * b), with p >= 0
+    Hamacher(F),
+}
+
+impl<F: Float> SNorm<F> {
+    pub fn apply(&self, a: F, b: F) -> F {
+        match *self {
+            SNorm::Max => a.max(b),
+            SNorm::ProbabilisticSum => a + b - a * b,
+            SNorm::BoundedSum => (a + b).min(F::ONE),
+            SNorm::Drastic => {
+                if a == F::ZERO {
+                    b
+                } else if b == F::ZERO {
+                    a
+                } else {
+                    F::ONE
+                }
+            }
+            SNorm::Einstein => (a + b) / (F::ONE + a * b),
+            SNorm::Hamacher(p) => {
+                let den = F::ONE + (p - F::ONE) * a * b;
+                if den == F::ZERO {
+                    F::ONE
+                } else {
+                    (a + b + (p - F::TWO) * a * b) / den
+                }
+            }
+        }
+    }
+}
+
+// Mamdani implication: shapes a consequent term by the rule firing strength
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Implication {
+    // min(fire, mu), clipping (classic Mamdani)
+    #[default]
+    Min,
+    // fire * mu, scaling (Larsen)
+    Product,
+    // max(0, fire + mu - 1)
+    Lukasiewicz,
+}
+
+impl Implication {
+    pub fn apply<F: Float>(&self, fire: F, mu: F) -> F {
+        match *self {
+            Implication::Min => fire.min(mu),
+            Implication::Product => fire * mu,
+            Implication::Lukasiewicz => (fire + mu - F::ONE).max(F::ZERO),
+        }
+    }
+}
+
+// Mamdani aggregation: combines implied consequents of all rules into one set
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Aggregation {
+    // max(acc, mu)
+    #[default]
+    Max,
+    // min(1, acc + mu)
+    BoundedSum,
+    // acc + mu - acc * mu
+    ProbabilisticSum,
+    // acc + mu, divided by the highest value when it exceeds 1 (see `normalize`)
+    NormalizedSum,
+}
+
+impl Aggregation {
+    pub fn apply<F: Float>(&self, acc: F, mu: F) -> F {
+        match *self {
+            Aggregation::Max => acc.max(mu),
+            Aggregation::BoundedSum => (acc + mu).min(F::ONE),
+            Aggregation::ProbabilisticSum => acc + mu - acc * mu,
+            Aggregation::NormalizedSum => acc + mu,
+        }
+    }
+
+    // Final pass over the aggregated curve once every rule has been applied
+    pub fn normalize<F: Float>(&self, agg: &mut [F]) {
+        if *self == Aggregation::NormalizedSum {
+            let max = agg.iter().copied().fold(F::ZERO, F::max);
+            if max > F::ONE {
+                for mu in agg.iter_mut() {
+                    *mu /= max;
+                }
+            }
+        }
+    }
+}
+
+// Fuzzy complement (NOT) applied to negated antecedent clauses
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Complement<F: Float = f64> {
+    // 1 - mu
+    #[default]
+    Standard,
+    // (1 - mu) / (1 + lambda * mu), with lambda > -1
+    Sugeno(F),
+    // (1 - mu^w)^(1/w), with w > 0
+    Yager(F),
+}
+
+impl<F: Float> Complement<F> {
+    pub fn apply(&self, mu: F) -> F {
+        match *self {
+            Complement::Standard => F::ONE - mu,
+            Complement::Sugeno(lambda) => (F::ONE - mu) / (F::ONE + lambda * mu),
+            Complement::Yager(w) => (F::ONE - mu.powf(w)).powf(F::ONE / w),
+        }
+    }
+}
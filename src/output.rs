@@ -1,15 +1,21 @@
+use crate::float::Float;
 use crate::variable::Range;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[derive(Debug)]
-pub struct OutputResult {
+pub struct OutputResult<F: Float = f64> {
     pub variable_name: String,
-    pub range: Range,
-    pub value: Vec<f64>,
+    pub range: Range<F>,
+    pub value: Vec<F>,
     pub best_term: Option<String>,
     pub term_kind: Option<String>,
 }
 
-impl OutputResult {
+impl<F: Float> OutputResult<F> {
     pub fn describe(&self) -> String {
         let val_str = if let Some(v) = self.value.first() {
             format!("{:.3}", v)
@@ -0,0 +1,483 @@
+/*
+Textual rule parser.
+
+Grammar (keywords are case-insensitive):
+
+  rule        := IF expression THEN consequents [WITH number]
+  expression  := and_expr (OR and_expr)*
+  and_expr    := unary (AND unary)*
+  unary       := NOT unary | '(' expression ')' | variable IS [NOT] term
+  consequents := variable IS term ((AND | ',') variable IS term)*
+
+Example:
+
+  IF service IS poor OR food IS rancid THEN tip IS cheap WITH 0.8
+
+Variables are resolved by name against the system inputs (antecedent) and
+outputs (consequent). Flat AND / OR rules touching every input at most once
+become positional rules, anything else keeps the parsed expression tree.
+*/
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use core::ops::Range;
+
+use crate::float::Float;
+use crate::rule::{Connective, Expression, Rule};
+use crate::variable::LinguisticVariable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleParseErrorKind {
+    UnexpectedToken { expected: String, found: String },
+    UnexpectedEnd { expected: String },
+    UnknownInput(String),
+    UnknownOutput(String),
+    UnknownTerm { variable: String, term: String },
+    DuplicateOutput(String),
+    InvalidWeight(String),
+}
+
+impl fmt::Display for RuleParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseErrorKind::UnexpectedToken { expected, found } => {
+                write!(f, "expected {}, found '{}'", expected, found)
+            }
+            RuleParseErrorKind::UnexpectedEnd { expected } => {
+                write!(f, "expected {}, found end of rule", expected)
+            }
+            RuleParseErrorKind::UnknownInput(name) => {
+                write!(f, "unknown input variable '{}'", name)
+            }
+            RuleParseErrorKind::UnknownOutput(name) => {
+                write!(f, "unknown output variable '{}'", name)
+            }
+            RuleParseErrorKind::UnknownTerm { variable, term } => {
+                write!(f, "unknown term '{}' of variable '{}'", term, variable)
+            }
+            RuleParseErrorKind::DuplicateOutput(name) => {
+                write!(f, "output variable '{}' used twice", name)
+            }
+            RuleParseErrorKind::InvalidWeight(text) => {
+                write!(
+                    f,
+                    "rule weight must be a number within [0, 1], got '{}'",
+                    text
+                )
+            }
+        }
+    }
+}
+
+// Parse failure with the byte range of the offending text in the rule string
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+#[error("{kind} at {}..{}", span.start, span.end)]
+pub struct RuleParseError {
+    pub kind: RuleParseErrorKind,
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    LParen,
+    RParen,
+    Comma,
+}
+
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    span: Range<usize>,
+}
+
+fn tokenize(text: &str) -> Vec<Spanned> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | ',') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push(Spanned {
+                    token: Token::Word(text[start..end].to_string()),
+                    span: start..end,
+                });
+                continue;
+            }
+        };
+        tokens.push(Spanned {
+            token,
+            span: start..start + 1,
+        });
+    }
+    tokens
+}
+
+struct Parser<'a, F: Float> {
+    tokens: Vec<Spanned>,
+    pos: usize,
+    end: usize,
+    inputs: &'a [LinguisticVariable<F>],
+    outputs: &'a [LinguisticVariable<F>],
+}
+
+impl<F: Float> Parser<'_, F> {
+    fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Spanned { token: Token::Word(w), .. }) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn error_here(&self, expected: &str) -> RuleParseError {
+        match self.peek() {
+            Some(spanned) => RuleParseError {
+                kind: RuleParseErrorKind::UnexpectedToken {
+                    expected: expected.to_string(),
+                    found: match &spanned.token {
+                        Token::Word(w) => w.clone(),
+                        Token::LParen => "(".to_string(),
+                        Token::RParen => ")".to_string(),
+                        Token::Comma => ",".to_string(),
+                    },
+                },
+                span: spanned.span.clone(),
+            },
+            None => RuleParseError {
+                kind: RuleParseErrorKind::UnexpectedEnd {
+                    expected: expected.to_string(),
+                },
+                span: self.end..self.end,
+            },
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), RuleParseError> {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error_here(keyword))
+        }
+    }
+
+    fn expect_word(&mut self, expected: &str) -> Result<(String, Range<usize>), RuleParseError> {
+        match self.peek() {
+            Some(Spanned {
+                token: Token::Word(w),
+                span,
+            }) if !is_keyword(w) => {
+                let result = (w.clone(), span.clone());
+                self.pos += 1;
+                Ok(result)
+            }
+            _ => Err(self.error_here(expected)),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expression, RuleParseError> {
+        let mut operands = vec![self.and_expression()?];
+        while self.peek_keyword("OR") {
+            self.pos += 1;
+            operands.push(self.and_expression()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            Expression::Or(operands)
+        })
+    }
+
+    fn and_expression(&mut self) -> Result<Expression, RuleParseError> {
+        let mut operands = vec![self.unary()?];
+        while self.peek_keyword("AND") {
+            self.pos += 1;
+            operands.push(self.unary()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            Expression::And(operands)
+        })
+    }
+
+    fn unary(&mut self) -> Result<Expression, RuleParseError> {
+        if self.peek_keyword("NOT") {
+            self.pos += 1;
+            return Ok(!self.unary()?);
+        }
+        if matches!(
+            self.peek(),
+            Some(Spanned {
+                token: Token::LParen,
+                ..
+            })
+        ) {
+            self.pos += 1;
+            let inner = self.expression()?;
+            match self.peek() {
+                Some(Spanned {
+                    token: Token::RParen,
+                    ..
+                }) => {
+                    self.pos += 1;
+                    return Ok(inner);
+                }
+                _ => return Err(self.error_here("')'")),
+            }
+        }
+        self.clause()
+    }
+
+    fn clause(&mut self) -> Result<Expression, RuleParseError> {
+        let (variable, var_span) = self.expect_word("input variable")?;
+        let var = self
+            .inputs
+            .iter()
+            .find(|v| v.name == variable)
+            .ok_or(RuleParseError {
+                kind: RuleParseErrorKind::UnknownInput(variable.clone()),
+                span: var_span,
+            })?;
+        self.expect_keyword("IS")?;
+        let negated = self.peek_keyword("NOT");
+        if negated {
+            self.pos += 1;
+        }
+        let (term, term_span) = self.expect_word("term")?;
+        if var.term(&term).is_none() {
+            return Err(RuleParseError {
+                kind: RuleParseErrorKind::UnknownTerm { variable, term },
+                span: term_span,
+            });
+        }
+        let clause = Expression::is(variable, term);
+        Ok(if negated { !clause } else { clause })
+    }
+
+    fn consequents(&mut self) -> Result<Vec<String>, RuleParseError> {
+        let mut consequent = vec![String::new(); self.outputs.len()];
+        loop {
+            let (variable, var_span) = self.expect_word("output variable")?;
+            let out_idx =
+                self.outputs
+                    .iter()
+                    .position(|v| v.name == variable)
+                    .ok_or(RuleParseError {
+                        kind: RuleParseErrorKind::UnknownOutput(variable.clone()),
+                        span: var_span.clone(),
+                    })?;
+            self.expect_keyword("IS")?;
+            let (term, term_span) = self.expect_word("term")?;
+            if self.outputs[out_idx].term(&term).is_none() {
+                return Err(RuleParseError {
+                    kind: RuleParseErrorKind::UnknownTerm { variable, term },
+                    span: term_span,
+                });
+            }
+            if !consequent[out_idx].is_empty() {
+                return Err(RuleParseError {
+                    kind: RuleParseErrorKind::DuplicateOutput(variable),
+                    span: var_span,
+                });
+            }
+            consequent[out_idx] = term;
+
+            match self.peek() {
+                Some(Spanned {
+                    token: Token::Comma,
+                    ..
+                }) => self.pos += 1,
+                _ if self.peek_keyword("AND") => self.pos += 1,
+                _ => break,
+            }
+        }
+
+        // Trailing outputs without a consequent can simply be left out
+        while consequent.last().is_some_and(|t| t.is_empty()) {
+            consequent.pop();
+        }
+        Ok(consequent)
+    }
+
+    fn weight(&mut self) -> Result<f64, RuleParseError> {
+        if !self.peek_keyword("WITH") {
+            return Ok(1.0);
+        }
+        self.pos += 1;
+        let (text, span) = self.expect_word("rule weight")?;
+        match text.parse::<f64>() {
+            Ok(w) if (0.0..=1.0).contains(&w) => Ok(w),
+            _ => Err(RuleParseError {
+                kind: RuleParseErrorKind::InvalidWeight(text),
+                span,
+            }),
+        }
+    }
+}
+
+fn is_keyword(word: &str) -> bool {
+    ["IF", "THEN", "IS", "NOT", "AND", "OR", "WITH"]
+        .iter()
+        .any(|k| word.eq_ignore_ascii_case(k))
+}
+
+// Single "variable IS [NOT] term" clause, as (variable, term, negated)
+fn as_clause(expression: &Expression) -> Option<(&str, &str, bool)> {
+    match expression {
+        Expression::Is { variable, term } => Some((variable, term, false)),
+        Expression::Not(inner) => match inner.as_ref() {
+            Expression::Is { variable, term } => Some((variable, term, true)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Positional form of flat rules, None when the expression tree is needed
+fn to_positional<F: Float>(
+    expression: &Expression,
+    inputs: &[LinguisticVariable<F>],
+    consequent: &[String],
+) -> Option<Rule> {
+    let (operands, connective) = match expression {
+        Expression::And(operands) => (operands.as_slice(), Connective::And),
+        Expression::Or(operands) => (operands.as_slice(), Connective::Or),
+        clause => (core::slice::from_ref(clause), Connective::And),
+    };
+
+    let mut antecedent: Vec<Option<String>> = vec![None; inputs.len()];
+    let mut negated = vec![false; inputs.len()];
+    for operand in operands {
+        let (variable, term, not) = as_clause(operand)?;
+        let i = inputs.iter().position(|v| v.name == variable)?;
+        if antecedent[i].is_some() {
+            return None;
+        }
+        antecedent[i] = Some(term.to_string());
+        negated[i] = not;
+    }
+
+    let mut rule = Rule::new(antecedent, consequent.to_vec(), connective);
+    if negated.iter().any(|n| *n) {
+        rule.negated = negated;
+    }
+    Some(rule)
+}
+
+pub fn parse_rule<F: Float>(
+    text: &str,
+    inputs: &[LinguisticVariable<F>],
+    outputs: &[LinguisticVariable<F>],
+) -> Result<Rule, RuleParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(text),
+        pos: 0,
+        end: text.len(),
+        inputs,
+        outputs,
+    };
+
+    parser.expect_keyword("IF")?;
+    let expression = parser.expression()?;
+    parser.expect_keyword("THEN")?;
+    let consequent = parser.consequents()?;
+    let weight = parser.weight()?;
+    if parser.peek().is_some() {
+        return Err(parser.error_here("end of rule"));
+    }
+
+    let mut rule = to_positional(&expression, inputs, &consequent)
+        .unwrap_or_else(|| Rule::from_expression(expression, consequent));
+    rule.weight = weight;
+    Ok(rule)
+}
+
+fn format_expression(expression: &Expression, nested: bool) -> Option<String> {
+    let join = |operands: &[Expression], keyword: &str| -> Option<String> {
+        if operands.is_empty() {
+            return None;
+        }
+        let parts = operands
+            .iter()
+            .map(|e| format_expression(e, true))
+            .collect::<Option<Vec<_>>>()?;
+        let text = parts.join(keyword);
+        Some(if nested && operands.len() > 1 {
+            format!("({})", text)
+        } else {
+            text
+        })
+    };
+    match expression {
+        Expression::Is { variable, term } => Some(format!("{} IS {}", variable, term)),
+        Expression::Not(inner) => match inner.as_ref() {
+            Expression::Is { variable, term } => Some(format!("{} IS NOT {}", variable, term)),
+            other => Some(format!("NOT {}", format_expression(other, true)?)),
+        },
+        Expression::And(operands) => join(operands, " AND "),
+        Expression::Or(operands) => join(operands, " OR "),
+    }
+}
+
+// Rule text accepted by `parse_rule`, None when the antecedent is empty
+// (e.g. a positional rule made of wildcards only). Wildcards of positional
+// rules are left out, as in the MATLAB convention.
+pub fn format_rule<F: Float>(
+    rule: &Rule,
+    inputs: &[LinguisticVariable<F>],
+    outputs: &[LinguisticVariable<F>],
+) -> Option<String> {
+    let expression = match &rule.expression {
+        Some(expression) => expression.clone(),
+        None => {
+            let clauses: Vec<Expression> = inputs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, var)| {
+                    let term = rule.antecedent.get(i).cloned().flatten()?;
+                    let clause = Expression::is(var.name.clone(), term);
+                    Some(if rule.is_negated(i) { !clause } else { clause })
+                })
+                .collect();
+            match rule.connective {
+                Connective::And => Expression::And(clauses),
+                Connective::Or => Expression::Or(clauses),
+            }
+        }
+    };
+
+    let consequents: Vec<String> = outputs
+        .iter()
+        .zip(rule.consequent.iter())
+        .filter(|(_, term)| !term.is_empty())
+        .map(|(var, term)| format!("{} IS {}", var.name, term))
+        .collect();
+
+    let mut text = format!(
+        "IF {} THEN {}",
+        format_expression(&expression, false)?,
+        consequents.join(", ")
+    );
+    if rule.weight != 1.0 {
+        text.push_str(&format!(" WITH {}", rule.weight));
+    }
+    Some(text)
+}
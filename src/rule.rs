@@ -1,16 +1,115 @@
-#[derive(Debug, Clone, Copy)]
+#[cfg(feature = "alloc")]
+use crate::fis::FisError;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Connective {
+    #[default]
     And,
     Or,
 }
 
+// Antecedent expression tree, referencing input variables by name:
+// (service IS poor AND food IS rancid) OR NOT (ambience IS nice)
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expression {
+    Is { variable: String, term: String },
+    Not(Box<Expression>),
+    And(Vec<Expression>), // combined with the system t-norm (empty = fully true)
+    Or(Vec<Expression>),  // combined with the system s-norm (empty = fully false)
+}
+
+#[cfg(feature = "alloc")]
+impl Expression {
+    pub fn is(variable: impl Into<String>, term: impl Into<String>) -> Self {
+        Expression::Is {
+            variable: variable.into(),
+            term: term.into(),
+        }
+    }
+
+    pub fn and(operands: Vec<Expression>) -> Self {
+        Expression::And(operands)
+    }
+
+    pub fn or(operands: Vec<Expression>) -> Self {
+        Expression::Or(operands)
+    }
+}
+
+// !expression builds "NOT expression"
+#[cfg(feature = "alloc")]
+impl core::ops::Not for Expression {
+    type Output = Expression;
+
+    fn not(self) -> Self::Output {
+        Expression::Not(Box::new(self))
+    }
+}
+
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rule {
-    pub antecedent: Vec<Option<String>>, // term names per input variable (None = wildcard)
-    pub consequent: Vec<String>,         // term names per output variable
-    pub connective: Connective,          // connective type: And, Or
+    // term names per input variable (None = wildcard)
+    #[cfg_attr(feature = "serde", serde(default, with = "wildcards"))]
+    pub antecedent: Vec<Option<String>>,
+    // term names per output variable (empty = none)
+    pub consequent: Vec<String>,
+    // connective type: And, Or
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub connective: Connective,
+    // certainty factor in [0, 1] scaling the firing strength
+    #[cfg_attr(feature = "serde", serde(default = "default_weight"))]
+    pub weight: f64,
+    // per input variable: true = IS NOT (missing = false)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub negated: Vec<bool>,
+    // when set, replaces antecedent, negated and connective
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub expression: Option<Expression>,
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+fn default_weight() -> f64 {
+    1.0
+}
+
+// wildcards are written as "" (formats like TOML have no null), null is accepted on input
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod wildcards {
+    use alloc::{string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        antecedent: &[Option<String>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(antecedent.iter().map(|term| term.as_deref().unwrap_or("")))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Option<String>>, D::Error> {
+        let antecedent = Vec::<Option<String>>::deserialize(deserializer)?;
+        Ok(antecedent
+            .into_iter()
+            .map(|term| term.filter(|name| !name.is_empty()))
+            .collect())
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl Rule {
     pub fn new(
         antecedent: Vec<Option<String>>,
@@ -21,6 +120,41 @@ impl Rule {
             antecedent,
             consequent,
             connective,
+            weight: 1.0,
+            negated: Vec::new(),
+            expression: None,
+        }
+    }
+
+    pub fn from_expression(expression: Expression, consequent: Vec<String>) -> Self {
+        Self {
+            antecedent: Vec::new(),
+            consequent,
+            connective: Connective::And,
+            weight: 1.0,
+            negated: Vec::new(),
+            expression: Some(expression),
+        }
+    }
+
+    // Turn the clause on the given input into "IS NOT"
+    pub fn negate(mut self, input: usize) -> Self {
+        if self.negated.len() <= input {
+            self.negated.resize(input + 1, false);
+        }
+        self.negated[input] = true;
+        self
+    }
+
+    pub fn is_negated(&self, input: usize) -> bool {
+        self.negated.get(input).copied().unwrap_or(false)
+    }
+
+    pub fn with_weight(mut self, weight: f64) -> Result<Self, FisError> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(FisError::InvalidRuleWeight(weight));
         }
+        self.weight = weight;
+        Ok(self)
     }
 }
@@ -1,24 +1,27 @@
+use crate::float::Float;
 use crate::membership::MembershipKind;
+use alloc::string::String;
 
 #[derive(Debug, Clone)]
-pub struct Term {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Term<F: Float = f64> {
     pub name: String,
-    pub kind: MembershipKind,
+    pub kind: MembershipKind<F>,
 }
 
-impl Term {
-    pub fn new(name: impl Into<String>, kind: MembershipKind) -> Self {
+impl<F: Float> Term<F> {
+    pub fn new(name: impl Into<String>, kind: MembershipKind<F>) -> Self {
         Self {
             name: name.into(),
             kind,
         }
     }
 
-    pub fn degree(&self, x: f64) -> f64 {
+    pub fn degree(&self, x: F) -> F {
         self.kind.degree(x)
     }
 
-    pub fn membership(&self, x: &Vec<f64>) -> f64 {
+    pub fn membership(&self, x: &[F]) -> F {
         self.kind.degree(*x.first().unwrap())
     }
 }
@@ -0,0 +1,380 @@
+/*
+Structural validation of a FuzzyInferenceSystem.
+
+Every problem found is reported, not only the first one, so a broken model
+can be fixed in a single pass:
+
+    for diagnostic in system.validate() {
+        eprintln!("{diagnostic}");
+    }
+
+Rules are identified by their index in `rules`, terms by variable and term name.
+*/
+
+use crate::{
+    fis::FuzzyInferenceSystem,
+    float::Float,
+    membership::MembershipKind,
+    rule::{Expression, Rule},
+    variable::{LinguisticVariable, Range},
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    #[error("variable {variable}: range min must be below max, got [{min}, {max}]")]
+    InvalidRange {
+        variable: String,
+        min: f64,
+        max: f64,
+    },
+    #[error("variable {variable}: duplicate term {term}")]
+    DuplicateTerm { variable: String, term: String },
+    #[error("variable {variable}, term {term}: membership parameters are not in ascending order")]
+    UnorderedParameters { variable: String, term: String },
+    #[error("variable {variable}, term {term}: membership function has zero width")]
+    ZeroWidth { variable: String, term: String },
+    #[error(
+        "variable {variable}, term {term}: membership degree must be within [0, 1], got {degree}"
+    )]
+    DegreeOutOfRange {
+        variable: String,
+        term: String,
+        degree: f64,
+    },
+    #[error("variable {variable}, term {term}: Gauss sigma must be positive, got {sigma}")]
+    NonPositiveSigma {
+        variable: String,
+        term: String,
+        sigma: f64,
+    },
+    #[error("variable {variable}, term {term}: sigmoid slope must not be zero")]
+    ZeroSlope { variable: String, term: String },
+    #[error("variable {variable}, term {term}: bell slope b must be positive, got {slope}")]
+    NonPositiveSlope {
+        variable: String,
+        term: String,
+        slope: f64,
+    },
+    #[error(
+        "variable {variable}, term {term}: expected {expected} linear coefficients, got {got}"
+    )]
+    LinearArity {
+        variable: String,
+        term: String,
+        expected: usize,
+        got: usize,
+    },
+    #[error("rule {rule}: expected at most {expected} antecedent clauses, got {got}")]
+    AntecedentArity {
+        rule: usize,
+        expected: usize,
+        got: usize,
+    },
+    #[error("rule {rule}: expected at most {expected} consequents, got {got}")]
+    ConsequentArity {
+        rule: usize,
+        expected: usize,
+        got: usize,
+    },
+    #[error("rule {rule}: unknown variable {variable}")]
+    UnknownVariable { rule: usize, variable: String },
+    #[error("rule {rule}: variable {variable} has no term {term}")]
+    UnknownTerm {
+        rule: usize,
+        variable: String,
+        term: String,
+    },
+    #[error("rule {rule}: weight must be within [0, 1], got {weight}")]
+    InvalidWeight { rule: usize, weight: f64 },
+}
+
+pub fn validate<F: Float>(fis: &FuzzyInferenceSystem<F>) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for var in fis.inputs.iter().chain(&fis.outputs) {
+        validate_variable(var, &mut diagnostics);
+    }
+    for var in &fis.outputs {
+        for term in &var.terms {
+            if let MembershipKind::Linear { coefficients, .. } = &term.kind {
+                if coefficients.len() != fis.inputs.len() {
+                    diagnostics.push(Diagnostic::LinearArity {
+                        variable: var.name.clone(),
+                        term: term.name.clone(),
+                        expected: fis.inputs.len(),
+                        got: coefficients.len(),
+                    });
+                }
+            }
+        }
+    }
+    for (index, rule) in fis.rules.iter().enumerate() {
+        validate_rule(fis, index, rule, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+fn validate_variable<F: Float>(var: &LinguisticVariable<F>, diagnostics: &mut Vec<Diagnostic>) {
+    let Range { min, max } = var.range;
+    if min >= max || min.is_nan() || max.is_nan() {
+        diagnostics.push(Diagnostic::InvalidRange {
+            variable: var.name.clone(),
+            min: min.to_f64(),
+            max: max.to_f64(),
+        });
+    }
+
+    for (i, term) in var.terms.iter().enumerate() {
+        if var.terms[..i].iter().any(|t| t.name == term.name) {
+            diagnostics.push(Diagnostic::DuplicateTerm {
+                variable: var.name.clone(),
+                term: term.name.clone(),
+            });
+        }
+
+        let points: &[F] = match &term.kind {
+            MembershipKind::Triangle { a, b, c } => &[*a, *b, *c],
+            MembershipKind::Trapezoid { a, b, c, d } => &[*a, *b, *c, *d],
+            // a == b is a crisp step, the open end keeps the shoulder from having zero width
+            MembershipKind::LeftShoulder { a, b } | MembershipKind::RightShoulder { a, b } => {
+                if a > b || a.is_nan() || b.is_nan() {
+                    diagnostics.push(Diagnostic::UnorderedParameters {
+                        variable: var.name.clone(),
+                        term: term.name.clone(),
+                    });
+                }
+                continue;
+            }
+            MembershipKind::Polygon { points } => {
+                let xs: Vec<F> = points.iter().map(|(x, _)| *x).collect();
+                validate_points(var, &term.name, &xs, diagnostics);
+                validate_degrees(
+                    var,
+                    &term.name,
+                    points.iter().map(|(_, mu)| *mu),
+                    diagnostics,
+                );
+                continue;
+            }
+            MembershipKind::Sampled { min, max, values } => {
+                let bounds = if values.is_empty() {
+                    &[][..]
+                } else {
+                    &[*min, *max]
+                };
+                validate_points(var, &term.name, bounds, diagnostics);
+                validate_degrees(var, &term.name, values.iter().copied(), diagnostics);
+                continue;
+            }
+            MembershipKind::SShape { a, b } | MembershipKind::ZShape { a, b } => &[*a, *b],
+            // the core must lie within the support
+            MembershipKind::Custom(membership) => &[
+                membership.support().min,
+                membership.core().min,
+                membership.core().max,
+                membership.support().max,
+            ],
+            MembershipKind::PiShape { a, b, c, d } => &[*a, *b, *c, *d],
+            MembershipKind::Gauss { sigma, .. } => {
+                validate_sigmas(var, &term.name, &[*sigma], diagnostics);
+                continue;
+            }
+            MembershipKind::Gauss2 { sigma1, sigma2, .. } => {
+                validate_sigmas(var, &term.name, &[*sigma1, *sigma2], diagnostics);
+                continue;
+            }
+            MembershipKind::Bell { a, b, .. } => {
+                if *a == F::ZERO {
+                    diagnostics.push(Diagnostic::ZeroWidth {
+                        variable: var.name.clone(),
+                        term: term.name.clone(),
+                    });
+                }
+                if *b <= F::ZERO || b.is_nan() {
+                    diagnostics.push(Diagnostic::NonPositiveSlope {
+                        variable: var.name.clone(),
+                        term: term.name.clone(),
+                        slope: b.to_f64(),
+                    });
+                }
+                continue;
+            }
+            // a zero slope leaves a flat 0.5 (or 0.25) level
+            MembershipKind::Sigmoid { a, .. } => {
+                validate_slopes(var, &term.name, &[*a], diagnostics);
+                continue;
+            }
+            MembershipKind::DiffSigmoid { a1, a2, .. }
+            | MembershipKind::ProdSigmoid { a1, a2, .. } => {
+                validate_slopes(var, &term.name, &[*a1, *a2], diagnostics);
+                continue;
+            }
+            MembershipKind::Singleton { .. }
+            | MembershipKind::Constant { .. }
+            | MembershipKind::Linear { .. } => continue,
+        };
+
+        validate_points(var, &term.name, points, diagnostics);
+    }
+}
+
+// Shape corners must ascend and span a non-empty interval
+fn validate_points<F: Float>(
+    var: &LinguisticVariable<F>,
+    term: &str,
+    points: &[F],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !points.windows(2).all(|w| w[0] <= w[1]) {
+        diagnostics.push(Diagnostic::UnorderedParameters {
+            variable: var.name.clone(),
+            term: term.to_string(),
+        });
+    } else if points.first() == points.last() {
+        diagnostics.push(Diagnostic::ZeroWidth {
+            variable: var.name.clone(),
+            term: term.to_string(),
+        });
+    }
+}
+
+fn validate_degrees<F: Float>(
+    var: &LinguisticVariable<F>,
+    term: &str,
+    degrees: impl Iterator<Item = F>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for degree in degrees {
+        if !(F::ZERO..=F::ONE).contains(&degree) {
+            diagnostics.push(Diagnostic::DegreeOutOfRange {
+                variable: var.name.clone(),
+                term: term.to_string(),
+                degree: degree.to_f64(),
+            });
+        }
+    }
+}
+
+fn validate_sigmas<F: Float>(
+    var: &LinguisticVariable<F>,
+    term: &str,
+    sigmas: &[F],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for sigma in sigmas {
+        if *sigma <= F::ZERO || sigma.is_nan() {
+            diagnostics.push(Diagnostic::NonPositiveSigma {
+                variable: var.name.clone(),
+                term: term.to_string(),
+                sigma: sigma.to_f64(),
+            });
+        }
+    }
+}
+
+fn validate_slopes<F: Float>(
+    var: &LinguisticVariable<F>,
+    term: &str,
+    slopes: &[F],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for slope in slopes {
+        if *slope == F::ZERO || slope.is_nan() {
+            diagnostics.push(Diagnostic::ZeroSlope {
+                variable: var.name.clone(),
+                term: term.to_string(),
+            });
+        }
+    }
+}
+
+fn validate_rule<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    index: usize,
+    rule: &Rule,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !(0.0..=1.0).contains(&rule.weight) {
+        diagnostics.push(Diagnostic::InvalidWeight {
+            rule: index,
+            weight: rule.weight,
+        });
+    }
+
+    if let Some(expression) = &rule.expression {
+        validate_expression(fis, index, expression, diagnostics);
+    } else {
+        let got = rule.antecedent.len().max(rule.negated.len());
+        if got > fis.inputs.len() {
+            diagnostics.push(Diagnostic::AntecedentArity {
+                rule: index,
+                expected: fis.inputs.len(),
+                got,
+            });
+        }
+        for (var, term) in fis.inputs.iter().zip(&rule.antecedent) {
+            if let Some(term) = term {
+                check_term(index, var, term, diagnostics);
+            }
+        }
+    }
+
+    if rule.consequent.len() > fis.outputs.len() {
+        diagnostics.push(Diagnostic::ConsequentArity {
+            rule: index,
+            expected: fis.outputs.len(),
+            got: rule.consequent.len(),
+        });
+    }
+    for (var, term) in fis.outputs.iter().zip(&rule.consequent) {
+        // an empty name leaves the output out of the rule
+        if !term.is_empty() {
+            check_term(index, var, term, diagnostics);
+        }
+    }
+}
+
+fn validate_expression<F: Float>(
+    fis: &FuzzyInferenceSystem<F>,
+    index: usize,
+    expression: &Expression,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match expression {
+        Expression::Is { variable, term } => {
+            match fis.inputs.iter().find(|v| &v.name == variable) {
+                Some(var) => check_term(index, var, term, diagnostics),
+                None => diagnostics.push(Diagnostic::UnknownVariable {
+                    rule: index,
+                    variable: variable.clone(),
+                }),
+            }
+        }
+        Expression::Not(operand) => validate_expression(fis, index, operand, diagnostics),
+        Expression::And(operands) | Expression::Or(operands) => {
+            for operand in operands {
+                validate_expression(fis, index, operand, diagnostics);
+            }
+        }
+    }
+}
+
+fn check_term<F: Float>(
+    index: usize,
+    var: &LinguisticVariable<F>,
+    term: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if var.term(term).is_none() {
+        diagnostics.push(Diagnostic::UnknownTerm {
+            rule: index,
+            variable: var.name.clone(),
+            term: term.to_string(),
+        });
+    }
+}
@@ -1,29 +1,48 @@
+#[cfg(feature = "alloc")]
+use crate::defuzzifier::Defuzzifier;
+#[cfg(feature = "alloc")]
 use crate::fis::FisError;
+#[cfg(feature = "alloc")]
 use crate::term::Term;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::float::Float;
 
 #[derive(Debug, Clone, Copy)]
-pub struct Range {
-    pub min: f64,
-    pub max: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range<F: Float = f64> {
+    pub min: F,
+    pub max: F,
 }
 
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
-pub struct LinguisticVariable {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinguisticVariable<F: Float = f64> {
     pub name: String,
-    pub range: Range,
-    pub terms: Vec<Term>,
+    pub range: Range<F>,
+    pub terms: Vec<Term<F>>,
+    // overrides the system defuzzifier when used as an output
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub defuzzifier: Option<Defuzzifier<F>>,
 }
 
-impl LinguisticVariable {
-    pub fn new(name: impl Into<String>, range: Range) -> Self {
+#[cfg(feature = "alloc")]
+impl<F: Float> LinguisticVariable<F> {
+    pub fn new(name: impl Into<String>, range: Range<F>) -> Self {
         Self {
             name: name.into(),
             range,
             terms: Vec::new(),
+            defuzzifier: None,
         }
     }
 
-    pub fn add_term(&mut self, term: Term) -> Result<(), FisError> {
+    pub fn add_term(&mut self, term: Term<F>) -> Result<(), FisError> {
         if self.terms.iter().any(|t| t.name == term.name) {
             return Err(FisError::DuplicateTerm(term.name.clone()));
         }
@@ -31,7 +50,11 @@ impl LinguisticVariable {
         Ok(())
     }
 
-    pub fn term(&self, name: &str) -> Option<&Term> {
+    pub fn set_defuzzifier(&mut self, defuzzifier: Defuzzifier<F>) {
+        self.defuzzifier = Some(defuzzifier);
+    }
+
+    pub fn term(&self, name: &str) -> Option<&Term<F>> {
         self.terms.iter().find(|t| t.name == name)
     }
 }
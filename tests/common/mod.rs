@@ -0,0 +1,53 @@
+/*
+Fixtures shared by the integration tests.
+
+The example systems are loaded from the examples themselves, so the precision
+comparisons run on the systems the examples demonstrate. Each test crate uses a
+part of this module.
+*/
+#![allow(dead_code)]
+
+use fuzzy_logic_engine_rs::{
+    fis::{FisType, FuzzyInferenceSystem},
+    fis_format::from_fis_str,
+    float::Float,
+    membership::MembershipKind,
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+#[path = "../../examples/gas_burner.rs"]
+pub mod gas_burner;
+#[path = "../../examples/motor_control.rs"]
+pub mod motor_control;
+#[path = "../../examples/restaurant_tip_level.rs"]
+pub mod restaurant_tip_level;
+
+// Mamdani example systems: gas burner (singleton consequents), motor control
+// (trapezoids) and tip (Gauss terms exercise exp)
+pub fn example_systems() -> Vec<FuzzyInferenceSystem> {
+    vec![
+        gas_burner::system().unwrap(),
+        motor_control::system().unwrap(),
+        restaurant_tip_level::system().unwrap(),
+    ]
+}
+
+// The same system in another float type, through its .fis text
+pub fn with_float<F: Float>(fis: &FuzzyInferenceSystem) -> FuzzyInferenceSystem<F> {
+    let text = fis.to_fis(FisType::Mamdani).unwrap();
+    from_fis_str::<F>(&text).unwrap().0
+}
+
+pub fn variable(
+    name: &str,
+    min: f64,
+    max: f64,
+    terms: Vec<(&str, MembershipKind)>,
+) -> LinguisticVariable {
+    let mut var = LinguisticVariable::new(name, Range { min, max });
+    for (term, kind) in terms {
+        var.add_term(Term::new(term, kind)).unwrap();
+    }
+    var
+}
@@ -0,0 +1,366 @@
+mod common;
+
+use common::variable;
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    membership::MembershipKind,
+    operators::{Aggregation, Complement, Implication, SNorm, TNorm},
+    rule::{Connective, Expression, Rule},
+    variable::LinguisticVariable,
+};
+
+fn inputs() -> Vec<LinguisticVariable> {
+    vec![
+        variable(
+            "temperature",
+            0.0,
+            60.0,
+            vec![
+                (
+                    "cold",
+                    MembershipKind::Trapezoid {
+                        a: 0.0,
+                        b: 0.0,
+                        c: 15.0,
+                        d: 30.0,
+                    },
+                ),
+                (
+                    "hot",
+                    MembershipKind::Triangle {
+                        a: 20.0,
+                        b: 60.0,
+                        c: 60.0,
+                    },
+                ),
+            ],
+        ),
+        variable(
+            "humidity",
+            0.0,
+            100.0,
+            vec![
+                (
+                    "dry",
+                    MembershipKind::Gauss {
+                        sigma: 20.0,
+                        mu: 0.0,
+                    },
+                ),
+                (
+                    "wet",
+                    MembershipKind::Gauss {
+                        sigma: 20.0,
+                        mu: 100.0,
+                    },
+                ),
+            ],
+        ),
+    ]
+}
+
+fn rules(fis: &mut FuzzyInferenceSystem) {
+    fis.add_rule_str("IF temperature IS hot AND humidity IS NOT dry THEN speed IS fast")
+        .unwrap();
+    fis.add_rule_str("IF temperature IS cold OR humidity IS dry THEN speed IS slow WITH 0.7")
+        .unwrap();
+    fis.rules.push(Rule::new(
+        vec![None, Some("wet".into())],
+        vec!["fast".into()],
+        Connective::And,
+    ));
+    fis.rules.push(Rule::from_expression(
+        Expression::or(vec![
+            Expression::and(vec![
+                Expression::is("temperature", "cold"),
+                Expression::is("humidity", "wet"),
+            ]),
+            !Expression::is("temperature", "hot"),
+        ]),
+        vec!["slow".into()],
+    ));
+}
+
+fn mamdani() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("motor");
+    for var in inputs() {
+        fis.add_input(var);
+    }
+    fis.add_output(variable(
+        "speed",
+        0.0,
+        3000.0,
+        vec![
+            (
+                "slow",
+                MembershipKind::Triangle {
+                    a: 0.0,
+                    b: 500.0,
+                    c: 1500.0,
+                },
+            ),
+            (
+                "fast",
+                MembershipKind::Trapezoid {
+                    a: 1000.0,
+                    b: 2500.0,
+                    c: 3000.0,
+                    d: 3000.0,
+                },
+            ),
+        ],
+    ));
+    rules(&mut fis);
+    fis
+}
+
+fn sugeno() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("motor");
+    for var in inputs() {
+        fis.add_input(var);
+    }
+    fis.add_output(variable(
+        "speed",
+        0.0,
+        3000.0,
+        vec![
+            ("slow", MembershipKind::Constant { value: 500.0 }),
+            (
+                "fast",
+                MembershipKind::Linear {
+                    coefficients: vec![20.0, 5.0],
+                    constant: 1500.0,
+                },
+            ),
+        ],
+    ));
+    rules(&mut fis);
+    fis
+}
+
+fn tsukamoto() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("motor");
+    for var in inputs() {
+        fis.add_input(var);
+    }
+    fis.add_output(variable(
+        "speed",
+        0.0,
+        3000.0,
+        vec![
+            (
+                "slow",
+                MembershipKind::Triangle {
+                    a: 0.0,
+                    b: 0.0,
+                    c: 1500.0,
+                },
+            ),
+            (
+                "fast",
+                MembershipKind::Trapezoid {
+                    a: 1000.0,
+                    b: 3000.0,
+                    c: 3000.0,
+                    d: 3000.0,
+                },
+            ),
+        ],
+    ));
+    rules(&mut fis);
+    fis
+}
+
+const SAMPLES: [[f64; 2]; 5] = [
+    [0.0, 0.0],
+    [10.0, 80.0],
+    [25.0, 50.0],
+    [42.0, 45.0],
+    [60.0, 100.0],
+];
+
+fn assert_matches_compute(fis: &FuzzyInferenceSystem, fis_type: FisType) {
+    let compiled = fis.compile(fis_type).unwrap();
+    let mut workspace = compiled.workspace();
+    let mut outputs = [0.0];
+
+    for inputs in SAMPLES {
+        compiled
+            .compute_into(&mut workspace, &inputs, &mut outputs)
+            .unwrap();
+        assert_eq!(outputs.to_vec(), fis.compute(fis_type, &inputs).unwrap());
+        assert_eq!(compiled.compute(&inputs).unwrap(), outputs.to_vec());
+    }
+}
+
+#[test]
+fn test_compiled_mamdani_matches_compute() {
+    let mut fis = mamdani();
+    assert_matches_compute(&fis, FisType::Mamdani);
+
+    fis.set_and_method(TNorm::Product);
+    fis.set_or_method(SNorm::ProbabilisticSum);
+    fis.set_implication(Implication::Product);
+    fis.set_aggregation(Aggregation::NormalizedSum);
+    fis.set_complement(Complement::Sugeno(0.5));
+    assert_matches_compute(&fis, FisType::Mamdani);
+
+    for defuzzifier in [
+        Defuzzifier::Bisector,
+        Defuzzifier::MeanOfMaximum,
+        Defuzzifier::SmallestOfMaximum,
+        Defuzzifier::LargestOfMaximum,
+        Defuzzifier::WeightedAverage,
+        Defuzzifier::custom(|xs, mus| xs[mus.len() - 1]),
+    ] {
+        fis.outputs[0].set_defuzzifier(defuzzifier);
+        assert_matches_compute(&fis, FisType::Mamdani);
+    }
+}
+
+#[test]
+fn test_compiled_sugeno_and_tsukamoto_match_compute() {
+    assert_matches_compute(&sugeno(), FisType::Sugeno);
+    assert_matches_compute(&tsukamoto(), FisType::Tsukamoto);
+}
+
+#[test]
+fn test_compiled_plan_is_a_snapshot() {
+    let mut fis = mamdani();
+    let compiled = fis.compile(FisType::Mamdani).unwrap();
+    let before = compiled.compute(&[42.0, 45.0]).unwrap();
+
+    fis.rules.clear();
+    assert_eq!(compiled.compute(&[42.0, 45.0]).unwrap(), before);
+    assert_eq!(compiled.fis_type(), FisType::Mamdani);
+}
+
+#[test]
+fn test_compile_resolves_names_up_front() {
+    let mut fis = mamdani();
+    fis.rules.push(Rule::new(
+        vec![Some("warm".into()), None],
+        vec!["fast".into()],
+        Connective::And,
+    ));
+    assert!(matches!(
+        fis.compile(FisType::Mamdani),
+        Err(FisError::TermNotFound(name)) if name == "warm"
+    ));
+
+    let mut fis = mamdani();
+    fis.rules.push(Rule::from_expression(
+        Expression::is("pressure", "high"),
+        vec!["fast".into()],
+    ));
+    assert!(matches!(
+        fis.compile(FisType::Mamdani),
+        Err(FisError::VariableNotFound(name)) if name == "pressure"
+    ));
+
+    let mut fis = mamdani();
+    fis.rules[0].weight = 2.0;
+    assert!(matches!(
+        fis.compile(FisType::Mamdani),
+        Err(FisError::InvalidRuleWeight(_))
+    ));
+}
+
+#[test]
+fn test_compile_checks_consequent_kinds() {
+    assert!(matches!(
+        mamdani().compile(FisType::Sugeno),
+        Err(FisError::InvalidSugenoTerm(name)) if name == "fast"
+    ));
+    assert!(matches!(
+        mamdani().compile(FisType::Tsukamoto),
+        Err(FisError::NonMonotonicTerm(name)) if name == "slow"
+    ));
+}
+
+#[test]
+fn test_compute_into_checks_lengths() {
+    let compiled = mamdani().compile(FisType::Mamdani).unwrap();
+    let mut workspace = compiled.workspace();
+
+    assert!(matches!(
+        compiled.compute_into(&mut workspace, &[1.0], &mut [0.0]),
+        Err(FisError::InputLen {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        compiled.compute_into(&mut workspace, &[1.0, 2.0], &mut [0.0, 0.0]),
+        Err(FisError::OutputMismatch)
+    ));
+}
+
+#[test]
+fn test_workspace_of_another_plan_is_replaced() {
+    let fis = mamdani();
+    let sugeno = sugeno().compile(FisType::Sugeno).unwrap();
+    let compiled = fis.compile(FisType::Mamdani).unwrap();
+    let mut workspace = sugeno.workspace();
+    let mut outputs = [0.0];
+
+    compiled
+        .compute_into(&mut workspace, &[42.0, 45.0], &mut outputs)
+        .unwrap();
+    assert_eq!(
+        outputs.to_vec(),
+        fis.compute(FisType::Mamdani, &[42.0, 45.0]).unwrap()
+    );
+}
+
+#[test]
+fn test_compute_batch_rows_and_columns() {
+    let fis = mamdani();
+    let rows: Vec<Vec<f64>> = (0..500)
+        .map(|i| vec![(i % 61) as f64, (i % 101) as f64])
+        .collect();
+
+    let batch = fis.compute_batch(FisType::Mamdani, &rows).unwrap();
+    assert_eq!(batch.len(), rows.len());
+    for (row, out) in rows.iter().zip(&batch) {
+        assert_eq!(vec![*out], fis.compute(FisType::Mamdani, row).unwrap());
+    }
+
+    let compiled = fis.compile(FisType::Mamdani).unwrap();
+    let temperature: Vec<f64> = rows.iter().map(|r| r[0]).collect();
+    let humidity: Vec<f64> = rows.iter().map(|r| r[1]).collect();
+    let columns = compiled
+        .compute_batch_columns(&[&temperature, &humidity])
+        .unwrap();
+    assert_eq!(columns, vec![batch]);
+
+    assert!(compiled.compute_batch::<[f64; 2]>(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_compute_batch_checks_shapes() {
+    let compiled = sugeno().compile(FisType::Sugeno).unwrap();
+
+    assert!(matches!(
+        compiled.compute_batch(&[vec![1.0, 2.0], vec![1.0]]),
+        Err(FisError::InputLen {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        compiled.compute_batch_columns(&[&[1.0, 2.0]]),
+        Err(FisError::InputLen {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        compiled.compute_batch_columns(&[&[1.0, 2.0], &[3.0]]),
+        Err(FisError::InputLen {
+            expected: 2,
+            got: 1
+        })
+    ));
+}
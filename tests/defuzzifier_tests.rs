@@ -0,0 +1,146 @@
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    membership::MembershipKind as M,
+    rule::{Connective, Rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+fn heater_system() -> FuzzyInferenceSystem {
+    let mut temp = LinguisticVariable::new(
+        "temperature",
+        Range {
+            min: 0.0,
+            max: 40.0,
+        },
+    );
+    temp.add_term(Term::new(
+        "cold",
+        M::Triangle {
+            a: 0.0,
+            b: 0.0,
+            c: 20.0,
+        },
+    ))
+    .unwrap();
+    temp.add_term(Term::new(
+        "warm",
+        M::Triangle {
+            a: 10.0,
+            b: 40.0,
+            c: 40.0,
+        },
+    ))
+    .unwrap();
+
+    let mut power = LinguisticVariable::new(
+        "power",
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+    );
+    power
+        .add_term(Term::new(
+            "high",
+            M::Trapezoid {
+                a: 50.0,
+                b: 70.0,
+                c: 90.0,
+                d: 100.0,
+            },
+        ))
+        .unwrap();
+    power
+        .add_term(Term::new(
+            "low",
+            M::Triangle {
+                a: 0.0,
+                b: 10.0,
+                c: 40.0,
+            },
+        ))
+        .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("Heater");
+    fis.add_input(temp);
+    fis.add_output(power);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("cold".into())],
+            vec!["high".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("warm".into())],
+            vec!["low".into()],
+            Connective::And,
+        ),
+    ]);
+    fis
+}
+
+#[test]
+fn test_default_defuzzifier_is_centroid() {
+    let fis = heater_system();
+    assert!(matches!(fis.defuzzifier, Defuzzifier::Centroid));
+    assert!(fis.outputs[0].defuzzifier.is_none());
+}
+
+#[test]
+fn test_maximum_methods_pick_plateau_of_dominant_rule() {
+    // temperature = 5 -> cold 0.75, warm 0.0 -> only "high" plateau [70, 90] survives
+    let mut fis = heater_system();
+
+    fis.set_defuzzifier(Defuzzifier::SmallestOfMaximum);
+    let som = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    fis.set_defuzzifier(Defuzzifier::LargestOfMaximum);
+    let lom = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    fis.set_defuzzifier(Defuzzifier::MeanOfMaximum);
+    let mom = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+
+    // clipped at 0.75 the plateau spans [65, 92.5]
+    assert!((som - 65.0).abs() < 1.0);
+    assert!((lom - 92.5).abs() < 1.0);
+    assert!((mom - 78.75).abs() < 1.0);
+}
+
+#[test]
+fn test_weighted_average_uses_term_peaks() {
+    let mut fis = heater_system();
+    fis.set_defuzzifier(Defuzzifier::WeightedAverage);
+
+    // temperature = 15 -> cold 0.25 (peak 80), warm 1/6 (peak 10)
+    let out = fis.compute(FisType::Mamdani, &[15.0]).unwrap()[0];
+    let expected = (0.25 * 80.0 + (1.0 / 6.0) * 10.0) / (0.25 + 1.0 / 6.0);
+    assert!((out - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_bisector_differs_from_centroid_on_skewed_set() {
+    let mut fis = heater_system();
+    let centroid = fis.compute(FisType::Mamdani, &[15.0]).unwrap()[0];
+    fis.set_defuzzifier(Defuzzifier::Bisector);
+    let bisector = fis.compute(FisType::Mamdani, &[15.0]).unwrap()[0];
+    assert!(bisector > 0.0 && bisector < 100.0);
+    assert!((bisector - centroid).abs() > 1e-6);
+}
+
+#[test]
+fn test_output_variable_overrides_system_defuzzifier() {
+    let mut fis = heater_system();
+    fis.set_defuzzifier(Defuzzifier::Centroid);
+    fis.outputs[0].set_defuzzifier(Defuzzifier::custom(|xs, _mus| xs[0] - 1.0));
+
+    let out = fis.compute(FisType::Mamdani, &[15.0]).unwrap()[0];
+    assert_eq!(out, -1.0);
+}
+
+#[test]
+fn test_defuzzifier_debug_and_clone() {
+    let custom = Defuzzifier::custom(|_xs, _mus| 0.0);
+    let cloned = custom.clone();
+    assert_eq!(format!("{:?}", cloned), "Custom(..)");
+    assert_eq!(format!("{:?}", Defuzzifier::<f64>::Bisector), "Bisector");
+}
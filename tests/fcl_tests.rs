@@ -0,0 +1,305 @@
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    membership::MembershipKind,
+    operators::{Aggregation, Implication, SNorm, TNorm},
+    rule::{Connective, Rule},
+};
+
+const TIPPER: &str = "\
+FUNCTION_BLOCK tipper (* restaurant tip *)
+
+VAR_INPUT
+    service : REAL;
+    food : REAL;
+END_VAR
+
+VAR_OUTPUT
+    tip : REAL;
+END_VAR
+
+FUZZIFY service
+    TERM poor := (0, 1) (4, 0);
+    TERM good := (1, 0) (4, 1) (6, 1) (9, 0);
+    TERM excellent := (6, 0) (9, 1);
+    RANGE := (0 .. 10);
+END_FUZZIFY
+
+FUZZIFY food
+    TERM rancid := (0, 1) (1, 1) (3, 0);
+    TERM delicious := (7, 0) (9, 1);
+END_FUZZIFY
+
+DEFUZZIFY tip
+    TERM cheap := (0, 0) (5, 1) (10, 0);
+    TERM average := (10, 0) (15, 1) (20, 0);
+    TERM generous := (20, 0) (25, 1) (30, 0);
+    METHOD : COG;
+    DEFAULT := 0;
+END_DEFUZZIFY
+
+RULEBLOCK No1
+    AND : PROD;
+    ACT : PROD;
+    ACCU : NSUM;
+    // rules
+    RULE 1 : IF service IS poor OR food IS rancid THEN tip IS cheap;
+    RULE 2 : IF service IS good THEN tip IS average;
+    RULE 3 : IF service IS excellent AND food IS delicious THEN tip IS generous WITH 0.5;
+END_RULEBLOCK
+
+END_FUNCTION_BLOCK
+";
+
+#[test]
+fn test_import_tipper_fcl() {
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fcl(TIPPER).unwrap();
+
+    assert_eq!(fis_type, FisType::Mamdani);
+    assert_eq!(fis.name, "tipper");
+    assert_eq!(fis.inputs.len(), 2);
+    assert_eq!(fis.outputs.len(), 1);
+
+    // explicit RANGE, otherwise the span of the finite term points
+    assert_eq!(fis.inputs[0].range.min, 0.0);
+    assert_eq!(fis.inputs[0].range.max, 10.0);
+    assert_eq!(fis.inputs[1].range.min, 0.0);
+    assert_eq!(fis.inputs[1].range.max, 9.0);
+    assert_eq!(fis.outputs[0].range.max, 30.0);
+
+    // ACT / ACCU / AND keywords
+    assert_eq!(fis.and_method, TNorm::Product);
+    assert_eq!(fis.or_method, SNorm::ProbabilisticSum);
+    assert_eq!(fis.implication, Implication::Product);
+    assert_eq!(fis.aggregation, Aggregation::NormalizedSum);
+    assert!(matches!(
+        fis.outputs[0].defuzzifier,
+        Some(Defuzzifier::Centroid)
+    ));
+
+    // shapes
+    assert!(matches!(
+        fis.inputs[0].terms[1].kind,
+        MembershipKind::Trapezoid { a, b, c, d } if (a, b, c, d) == (1.0, 4.0, 6.0, 9.0)
+    ));
+    let poor = fis.inputs[0].term("poor").unwrap();
+    assert!(matches!(
+        poor.kind,
+        MembershipKind::LeftShoulder { a, b } if (a, b) == (0.0, 4.0)
+    ));
+    assert_eq!(poor.degree(-1.0), 1.0);
+    assert_eq!(poor.degree(0.0), 1.0);
+    assert_eq!(poor.degree(2.0), 0.5);
+    let excellent = fis.inputs[0].term("excellent").unwrap();
+    assert_eq!(excellent.degree(10.0), 1.0);
+    assert_eq!(excellent.degree(12.0), 1.0);
+
+    // rules
+    assert_eq!(fis.rules.len(), 3);
+    assert!(matches!(fis.rules[0].connective, Connective::Or));
+    assert_eq!(fis.rules[2].weight, 0.5);
+
+    let result = fis.compute(fis_type, &[8.0, 8.0]).unwrap();
+    assert!(result[0] > 10.0 && result[0] < 30.0);
+}
+
+#[test]
+fn test_round_trip_preserves_system() {
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fcl(TIPPER).unwrap();
+    let text = fis.to_fcl(fis_type).unwrap();
+    let (again, again_type) = FuzzyInferenceSystem::from_fcl(&text).unwrap();
+
+    assert_eq!(again_type, fis_type);
+    assert_eq!(again.to_fcl(again_type).unwrap(), text);
+    for inputs in [[1.0, 1.0], [5.0, 5.0], [9.0, 8.5]] {
+        assert_eq!(
+            fis.compute(fis_type, &inputs).unwrap(),
+            again.compute(again_type, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_of_or_rule_with_wildcard() {
+    let (mut fis, fis_type) = FuzzyInferenceSystem::from_fcl(TIPPER).unwrap();
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("poor".into()), None],
+            vec!["cheap".into()],
+            Connective::Or,
+        ),
+        Rule::new(
+            vec![Some("good".into()), None],
+            vec!["average".into()],
+            Connective::And,
+        ),
+    ]);
+    let text = fis.to_fcl(fis_type).unwrap();
+    assert!(text.contains("RULE 1 : IF service IS poor THEN tip IS cheap;"));
+    let (again, again_type) = FuzzyInferenceSystem::from_fcl(&text).unwrap();
+
+    // service = 5: poor 0, good 1 -> only "average" fires, centered on 15
+    let result = again.compute(again_type, &[5.0, 5.0]).unwrap();
+    assert!((result[0] - 15.0).abs() < 1e-9);
+    for inputs in [[5.0, 5.0], [2.0, 1.0], [9.0, 8.5]] {
+        assert_eq!(
+            fis.compute(fis_type, &inputs).unwrap(),
+            again.compute(again_type, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_singletons_with_cogs_make_sugeno_system() {
+    let text = "\
+FUNCTION_BLOCK burner
+VAR_INPUT
+    oxi : REAL;
+END_VAR
+VAR_OUTPUT
+    power : REAL;
+END_VAR
+FUZZIFY oxi
+    TERM low := (0, 1) (3, 0);
+    TERM high := (0, 0) (3, 1);
+END_FUZZIFY
+DEFUZZIFY power
+    TERM weak := 20;
+    TERM strong := 80;
+    METHOD : COGS;
+    RANGE := (0 .. 100);
+END_DEFUZZIFY
+RULEBLOCK rules
+    AND : MIN;
+    ACCU : MAX;
+    RULE 1 : IF oxi IS low THEN power IS weak;
+    RULE 2 : IF oxi IS NOT low THEN power IS strong;
+END_RULEBLOCK
+END_FUNCTION_BLOCK
+";
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fcl(text).unwrap();
+    assert_eq!(fis_type, FisType::Sugeno);
+    assert!(matches!(
+        fis.outputs[0].terms[0].kind,
+        MembershipKind::Constant { value } if value == 20.0
+    ));
+    assert!(fis.rules[1].is_negated(0));
+
+    // oxi = 1 -> low 2/3, NOT low 1/3 -> 20 * 2/3 + 80 * 1/3
+    let result = fis.compute(fis_type, &[1.0]).unwrap();
+    assert!((result[0] - 40.0).abs() < 1e-9);
+
+    let exported = fis.to_fcl(fis_type).unwrap();
+    assert!(exported.contains("TERM weak := 20;"));
+    assert!(exported.contains("METHOD : COGS;"));
+    assert!(exported.contains("RULE 2 : IF oxi IS NOT low THEN power IS strong;"));
+}
+
+#[test]
+fn test_singletons_with_cog_stay_mamdani_singletons() {
+    let text = "\
+FUNCTION_BLOCK burner
+VAR_INPUT
+    oxi : REAL;
+END_VAR
+VAR_OUTPUT
+    power : REAL;
+END_VAR
+FUZZIFY oxi
+    TERM low := (0, 1) (3, 0);
+END_FUZZIFY
+DEFUZZIFY power
+    TERM weak := 20;
+    TERM strong := 80;
+    METHOD : COG;
+    RANGE := (0 .. 100);
+END_DEFUZZIFY
+RULEBLOCK rules
+    AND : MIN;
+    ACCU : MAX;
+    RULE 1 : IF oxi IS low THEN power IS weak;
+    RULE 2 : IF oxi IS NOT low THEN power IS strong;
+END_RULEBLOCK
+END_FUNCTION_BLOCK
+";
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fcl(text).unwrap();
+    assert_eq!(fis_type, FisType::Mamdani);
+    assert!(matches!(
+        fis.outputs[0].terms[1].kind,
+        MembershipKind::Singleton { value } if value == 80.0
+    ));
+
+    // oxi = 1 -> weak 2/3, strong 1/3: the exact weighted average of the points
+    let result = fis.compute(fis_type, &[1.0]).unwrap();
+    assert!((result[0] - 40.0).abs() < 1e-9);
+
+    let exported = fis.to_fcl(fis_type).unwrap();
+    assert!(exported.contains("TERM strong := 80;"));
+    assert!(exported.contains("METHOD : COG;"));
+}
+
+#[test]
+fn test_arbitrary_point_lists_become_polygons() {
+    let curved = TIPPER.replace(
+        "TERM good := (1, 0) (4, 1) (6, 1) (9, 0);",
+        "TERM good := (1, 0) (3, 0.6) (5, 1) (7, 0.4) (9, 0);",
+    );
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fcl(&curved).unwrap();
+    let good = fis.inputs[0].term("good").unwrap();
+    assert!(matches!(&good.kind, MembershipKind::Polygon { points } if points.len() == 5));
+    assert!((good.degree(2.0) - 0.3).abs() < 1e-12);
+    assert!((good.degree(6.0) - 0.7).abs() < 1e-12);
+    assert!(fis.validate().is_empty());
+
+    let text = fis.to_fcl(fis_type).unwrap();
+    assert!(text.contains("TERM good := (1, 0) (3, 0.6) (5, 1) (7, 0.4) (9, 0);"));
+    let (again, _) = FuzzyInferenceSystem::from_fcl(&text).unwrap();
+    assert_eq!(
+        fis.compute(fis_type, &[6.0, 8.0]).unwrap(),
+        again.compute(fis_type, &[6.0, 8.0]).unwrap()
+    );
+}
+
+#[test]
+fn test_import_reports_errors() {
+    let unknown_term = TIPPER.replace("THEN tip IS average", "THEN tip IS huge");
+    match FuzzyInferenceSystem::from_fcl(&unknown_term) {
+        Err(FisError::FclFormat { line, message }) => {
+            assert_eq!(line, 38);
+            assert!(message.contains("huge"));
+        }
+        other => panic!("Expected FclFormat, got {:?}", other.err()),
+    }
+
+    let unsupported = TIPPER.replace("ACT : PROD", "ACT : BDIF");
+    assert!(matches!(
+        FuzzyInferenceSystem::from_fcl(&unsupported),
+        Err(FisError::UnsupportedFclConstruct(_))
+    ));
+
+    let undeclared = TIPPER.replace("FUZZIFY food", "FUZZIFY drinks");
+    assert!(matches!(
+        FuzzyInferenceSystem::from_fcl(&undeclared),
+        Err(FisError::FclFormat { line: 19, .. })
+    ));
+}
+
+#[test]
+fn test_export_reports_unsupported_constructs() {
+    let (mut fis, fis_type) = FuzzyInferenceSystem::from_fcl(TIPPER).unwrap();
+    fis.set_or_method(SNorm::Einstein);
+    assert!(matches!(
+        fis.to_fcl(fis_type),
+        Err(FisError::UnsupportedFclConstruct(_))
+    ));
+
+    fis.set_or_method(SNorm::ProbabilisticSum);
+    fis.outputs[0].terms[0].kind = MembershipKind::Gauss {
+        sigma: 1.0,
+        mu: 5.0,
+    };
+    assert!(matches!(
+        fis.to_fcl(fis_type),
+        Err(FisError::UnsupportedFclConstruct(_))
+    ));
+}
@@ -0,0 +1,332 @@
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    membership::MembershipKind,
+    operators::{Implication, SNorm},
+    rule::{Connective, Expression, Rule},
+};
+
+const TIPPER: &str = "\
+[System]
+Name='tipper'
+Type='mamdani'
+Version=2.0
+NumInputs=2
+NumOutputs=1
+NumRules=3
+AndMethod='min'
+OrMethod='max'
+ImpMethod='min'
+AggMethod='max'
+DefuzzMethod='centroid'
+
+[Input1]
+Name='service'
+Range=[0 10]
+NumMFs=3
+MF1='poor':'gaussmf',[1.5 0]
+MF2='good':'gaussmf',[1.5 5]
+MF3='excellent':'gaussmf',[1.5 10]
+
+[Input2]
+Name='food'
+Range=[0 10]
+NumMFs=2
+MF1='rancid':'trapmf',[0 0 1 3]
+MF2='delicious':'trapmf',[7 9 10 10]
+
+[Output1]
+Name='tip'
+Range=[0 30]
+NumMFs=3
+MF1='cheap':'trimf',[0 5 10]
+MF2='average':'trimf',[10 15 20]
+MF3='generous':'trimf',[20 25 30]
+
+[Rules]
+1 1, 1 (1) : 2
+2 0, 2 (1) : 1
+3 2, 3 (0.5) : 2
+";
+
+#[test]
+fn test_import_tipper_fis() {
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(TIPPER).unwrap();
+
+    assert_eq!(fis_type, FisType::Mamdani);
+    assert_eq!(fis.name, "tipper");
+    assert_eq!(fis.inputs.len(), 2);
+    assert_eq!(fis.outputs.len(), 1);
+    assert_eq!(fis.inputs[1].name, "food");
+    assert_eq!(fis.outputs[0].range.max, 30.0);
+    assert!(matches!(
+        fis.inputs[0].terms[1].kind,
+        MembershipKind::Gauss { sigma, mu } if sigma == 1.5 && mu == 5.0
+    ));
+
+    assert_eq!(fis.rules.len(), 3);
+    assert_eq!(
+        fis.rules[0].antecedent,
+        vec![Some("poor".to_string()), Some("rancid".to_string())]
+    );
+    assert!(matches!(fis.rules[0].connective, Connective::Or));
+    assert_eq!(
+        fis.rules[1].antecedent,
+        vec![Some("good".to_string()), None]
+    );
+    assert_eq!(fis.rules[2].consequent, vec!["generous".to_string()]);
+    assert_eq!(fis.rules[2].weight, 0.5);
+
+    let result = fis.compute(fis_type, &[3.0, 8.0]).unwrap();
+    assert!(result[0] > 5.0 && result[0] < 25.0);
+}
+
+#[test]
+fn test_round_trip_preserves_system() {
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(TIPPER).unwrap();
+    let text = fis.to_fis(fis_type).unwrap();
+    let (again, again_type) = FuzzyInferenceSystem::from_fis(&text).unwrap();
+
+    assert_eq!(again_type, fis_type);
+    assert_eq!(again.to_fis(again_type).unwrap(), text);
+    for inputs in [[1.0, 1.0], [5.0, 5.0], [9.0, 8.5]] {
+        assert_eq!(
+            fis.compute(fis_type, &inputs).unwrap(),
+            again.compute(again_type, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_import_methods_negation_and_sugeno_consequents() {
+    let text = "\
+[System]
+Name='sugeno'
+Type='sugeno'
+NumInputs=1
+NumOutputs=1
+AndMethod='prod'
+OrMethod='probor'
+ImpMethod='prod'
+AggMethod='sum'
+DefuzzMethod='wtaver'
+
+[Input1]
+Name='x'
+Range=[0 10]
+MF1='high':'trimf',[0 10 10]
+
+[Output1]
+Name='y'
+Range=[0 100]
+MF1='line':'linear',[2 1]
+MF2='zero':'constant',[0]
+
+[Rules]
+-1, 1 (1) : 1
+1, 2 (1) : 1
+";
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(text).unwrap();
+    assert_eq!(fis_type, FisType::Sugeno);
+    assert_eq!(fis.or_method, SNorm::ProbabilisticSum);
+    assert_eq!(fis.implication, Implication::Product);
+    assert!(matches!(fis.defuzzifier, Defuzzifier::WeightedAverage));
+    assert!(fis.rules[0].is_negated(0));
+    assert!(matches!(
+        &fis.outputs[0].terms[0].kind,
+        MembershipKind::Linear { coefficients, constant } if coefficients == &vec![2.0] && *constant == 1.0
+    ));
+
+    // x = 2.5 -> NOT high 0.75 -> 6.0, high 0.25 -> 0.0
+    let result = fis.compute(fis_type, &[2.5]).unwrap();
+    assert!((result[0] - 0.75 * 6.0).abs() < 1e-9);
+
+    let exported = fis.to_fis(fis_type).unwrap();
+    assert!(exported.contains("-1, 1 (1) : 1"));
+    assert!(exported.contains("MF1='line':'linear',[2 1]"));
+}
+
+#[test]
+fn test_import_or_rule_with_dont_care() {
+    let text = "\
+[System]
+Name='dont_care'
+Type='sugeno'
+NumInputs=2
+NumOutputs=1
+AndMethod='min'
+OrMethod='max'
+DefuzzMethod='wtaver'
+
+[Input1]
+Name='a'
+Range=[0 10]
+MF1='lo':'trimf',[0 0 10]
+MF2='hi':'trimf',[0 10 10]
+
+[Input2]
+Name='b'
+Range=[0 10]
+MF1='any':'trimf',[0 5 10]
+
+[Output1]
+Name='y'
+Range=[0 10]
+MF1='low':'constant',[0]
+MF2='high':'constant',[10]
+
+[Rules]
+1 0, 2 (1) : 2
+2 0, 1 (1) : 2
+";
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(text).unwrap();
+    assert_eq!(fis.rules[0].antecedent, vec![Some("lo".to_string()), None]);
+    assert!(matches!(fis.rules[0].connective, Connective::Or));
+    let compiled = fis.compile(fis_type).unwrap();
+
+    // a = 10: lo 0, hi 1 -> the don't-care adds nothing, only "low" fires
+    assert_eq!(fis.compute(fis_type, &[10.0, 3.0]).unwrap(), vec![0.0]);
+    assert_eq!(compiled.compute(&[10.0, 3.0]).unwrap(), vec![0.0]);
+
+    // a = 4: lo 0.6 -> 10, hi 0.4 -> 0 => 0.6 * 10 / (0.6 + 0.4)
+    let result = fis.compute(fis_type, &[4.0, 3.0]).unwrap();
+    assert!((result[0] - 6.0).abs() < 1e-9);
+    assert_eq!(compiled.compute(&[4.0, 3.0]).unwrap(), result);
+}
+
+#[test]
+fn test_import_and_export_curved_shapes() {
+    let shapes = TIPPER
+        .replace("'gaussmf',[1.5 0]", "'zmf',[0 4]")
+        .replace("'gaussmf',[1.5 5]", "'gbellmf',[2 3 5]")
+        .replace("'gaussmf',[1.5 10]", "'smf',[6 10]")
+        .replace("'trapmf',[0 0 1 3]", "'sigmf',[-4 2]")
+        .replace("'trapmf',[7 9 10 10]", "'psigmf',[4 7 -4 11]")
+        .replace("'trimf',[0 5 10]", "'gauss2mf',[2 4 1 6]")
+        .replace("'trimf',[10 15 20]", "'pimf',[10 14 16 20]")
+        .replace("'trimf',[20 25 30]", "'dsigmf',[3 21 3 29]");
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(&shapes).unwrap();
+
+    assert!(matches!(
+        fis.inputs[0].terms[1].kind,
+        MembershipKind::Bell { a, b, c } if a == 2.0 && b == 3.0 && c == 5.0
+    ));
+    assert!(matches!(
+        fis.inputs[1].terms[1].kind,
+        MembershipKind::ProdSigmoid { a1, c1, a2, c2 } if a1 == 4.0 && c1 == 7.0 && a2 == -4.0 && c2 == 11.0
+    ));
+    assert!(matches!(
+        fis.outputs[0].terms[0].kind,
+        MembershipKind::Gauss2 { sigma1, mu1, sigma2, mu2 }
+            if sigma1 == 2.0 && mu1 == 4.0 && sigma2 == 1.0 && mu2 == 6.0
+    ));
+    assert!(fis.validate().is_empty());
+
+    let text = fis.to_fis(fis_type).unwrap();
+    for mf in [
+        "'zmf',[0 4]",
+        "'sigmf',[-4 2]",
+        "'pimf',[10 14 16 20]",
+        "'dsigmf',[3 21 3 29]",
+    ] {
+        assert!(text.contains(mf), "{} in {}", mf, text);
+    }
+    let (again, _) = FuzzyInferenceSystem::from_fis(&text).unwrap();
+    assert_eq!(
+        fis.compute(fis_type, &[7.0, 8.0]).unwrap(),
+        again.compute(fis_type, &[7.0, 8.0]).unwrap()
+    );
+
+    let wrong_arity = shapes.replace("'smf',[6 10]", "'smf',[6 8 10]");
+    assert!(matches!(
+        FuzzyInferenceSystem::from_fis(&wrong_arity),
+        Err(FisError::FisFormat { line: 20, .. })
+    ));
+}
+
+#[test]
+fn test_import_and_export_shoulders() {
+    let shoulders = TIPPER
+        .replace("'trapmf',[0 0 1 3]", "'linzmf',[1 3]")
+        .replace("'trapmf',[7 9 10 10]", "'linsmf',[7 9]");
+    let (fis, fis_type) = FuzzyInferenceSystem::from_fis(&shoulders).unwrap();
+
+    let rancid = &fis.inputs[1].terms[0].kind;
+    assert!(matches!(rancid, MembershipKind::LeftShoulder { a, b } if (*a, *b) == (1.0, 3.0)));
+    assert_eq!(rancid.degree(-5.0), 1.0);
+    let delicious = &fis.inputs[1].terms[1].kind;
+    assert!(matches!(delicious, MembershipKind::RightShoulder { a, b } if (*a, *b) == (7.0, 9.0)));
+    assert_eq!(delicious.degree(15.0), 1.0);
+
+    let text = fis.to_fis(fis_type).unwrap();
+    assert!(text.contains("MF1='rancid':'linzmf',[1 3]"));
+    assert!(text.contains("MF2='delicious':'linsmf',[7 9]"));
+}
+
+#[test]
+fn test_import_reports_unsupported_and_malformed_content() {
+    let unsupported = TIPPER.replace("'gaussmf',[1.5 0]", "'mymf',[2 4]");
+    match FuzzyInferenceSystem::from_fis(&unsupported) {
+        Err(FisError::UnsupportedFisConstruct(what)) => assert!(what.contains("mymf")),
+        other => panic!("Expected UnsupportedFisConstruct, got {:?}", other.err()),
+    }
+
+    let malformed = TIPPER.replace(
+        "MF2='delicious':'trapmf',[7 9 10 10]",
+        "MF2='delicious':'trapmf',[7 9 10]",
+    );
+    match FuzzyInferenceSystem::from_fis(&malformed) {
+        Err(FisError::FisFormat { line, .. }) => assert_eq!(line, 27),
+        other => panic!("Expected FisFormat, got {:?}", other.err()),
+    }
+
+    let bad_rule = TIPPER.replace("2 0, 2 (1) : 1", "2 0, 7 (1) : 1");
+    assert!(matches!(
+        FuzzyInferenceSystem::from_fis(&bad_rule),
+        Err(FisError::FisFormat { line: 39, .. })
+    ));
+}
+
+#[test]
+fn test_import_checks_counts_names_and_weights() {
+    let line_of = |text: &str| match FuzzyInferenceSystem::from_fis(text) {
+        Err(FisError::FisFormat { line, .. }) => line,
+        other => panic!("Expected FisFormat, got {:?}", other.err()),
+    };
+
+    assert_eq!(line_of(&TIPPER.replace("NumInputs=2", "NumInputs=3")), 5);
+    assert_eq!(line_of(&TIPPER.replace("NumOutputs=1", "NumOutputs=2")), 6);
+    assert_eq!(line_of(&TIPPER.replace("NumRules=3", "NumRules=4")), 7);
+    assert_eq!(line_of(&TIPPER.replace("NumMFs=2", "NumMFs=3")), 25);
+
+    // the line of the section header
+    assert_eq!(line_of(&TIPPER.replace("Name='tipper'\n", "")), 1);
+    assert_eq!(line_of(&TIPPER.replace("Name='food'\n", "")), 22);
+
+    assert_eq!(
+        line_of(&TIPPER.replace("3 2, 3 (0.5) : 2", "3 2, 3 (1.5) : 2")),
+        40
+    );
+    assert_eq!(
+        line_of(&TIPPER.replace("2 0, 2 (1) : 1", "2 0, 2 (-1) : 1")),
+        39
+    );
+}
+
+#[test]
+fn test_export_reports_unsupported_constructs() {
+    let (mut fis, fis_type) = FuzzyInferenceSystem::from_fis(TIPPER).unwrap();
+    assert!(matches!(
+        fis.to_fis(FisType::Tsukamoto),
+        Err(FisError::UnsupportedFisConstruct(_))
+    ));
+
+    fis.rules.push(Rule::from_expression(
+        Expression::is("service", "poor"),
+        vec!["cheap".into()],
+    ));
+    match fis.to_fis(fis_type) {
+        Err(FisError::UnsupportedFisConstruct(what)) => assert!(what.contains("rule 4")),
+        other => panic!("Expected UnsupportedFisConstruct, got {:?}", other),
+    }
+}
@@ -1,7 +1,9 @@
 use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
     fis::{FisError, FisType, FuzzyInferenceSystem},
-    membership::MembershipKind as M,
-    rule::{Connective, Rule},
+    fixed::{FixedFis, FixedRule, FixedVariable},
+    membership::{Membership, MembershipKind as M},
+    rule::{Connective, Expression, Rule},
     term::Term,
     variable::{LinguisticVariable, Range},
 };
@@ -25,7 +27,8 @@ fn test_simple_tip_system() {
             b: 5.0,
             c: 10.0,
         },
-    ));
+    ))
+    .unwrap();
     tip.add_term(Term::new(
         "generous",
         M::Triangle {
@@ -33,7 +36,8 @@ fn test_simple_tip_system() {
             b: 25.0,
             c: 30.0,
         },
-    ));
+    ))
+    .unwrap();
     system.add_output(tip);
 
     // Input: service
@@ -44,22 +48,26 @@ fn test_simple_tip_system() {
             max: 10.0,
         },
     );
-    service.add_term(Term::new(
-        "poor",
-        M::Triangle {
-            a: 0.0,
-            b: 0.0,
-            c: 5.0,
-        },
-    ));
-    service.add_term(Term::new(
-        "excellent",
-        M::Triangle {
-            a: 5.0,
-            b: 10.0,
-            c: 10.0,
-        },
-    ));
+    service
+        .add_term(Term::new(
+            "poor",
+            M::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 5.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "excellent",
+            M::Triangle {
+                a: 5.0,
+                b: 10.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
     system.add_input(service);
 
     // Rule: IF service is excellent THEN tip is generous
@@ -90,7 +98,8 @@ fn test_compute_verbose_returns_descriptive_output() {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))
+    .unwrap();
     temp.add_term(Term::new(
         "hot",
         M::Triangle {
@@ -98,7 +107,8 @@ fn test_compute_verbose_returns_descriptive_output() {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Define output variable
     let mut fan = LinguisticVariable::new(
@@ -115,7 +125,8 @@ fn test_compute_verbose_returns_descriptive_output() {
             b: 0.0,
             c: 5.0,
         },
-    ));
+    ))
+    .unwrap();
     fan.add_term(Term::new(
         "fast",
         M::Triangle {
@@ -123,7 +134,8 @@ fn test_compute_verbose_returns_descriptive_output() {
             b: 10.0,
             c: 10.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Build FIS
     let mut fis = FuzzyInferenceSystem::new("Test FIS");
@@ -182,7 +194,8 @@ fn test_input_length_error() {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))
+    .unwrap();
     temp.add_term(Term::new(
         "hot",
         M::Triangle {
@@ -190,7 +203,8 @@ fn test_input_length_error() {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))
+    .unwrap();
 
     let mut fis = FuzzyInferenceSystem::new("Test FIS");
     fis.add_input(temp);
@@ -225,7 +239,8 @@ fn test_term_not_found_error() {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Define output variable with only one term
     let mut fan = LinguisticVariable::new(
@@ -242,7 +257,8 @@ fn test_term_not_found_error() {
             b: 0.0,
             c: 5.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Build FIS
     let mut fis = FuzzyInferenceSystem::new("Test FIS");
@@ -286,7 +302,8 @@ fn test_wildcard_rule_triggers_none_branch() {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Define output variable
     let mut fan = LinguisticVariable::new(
@@ -303,7 +320,8 @@ fn test_wildcard_rule_triggers_none_branch() {
             b: 0.0,
             c: 5.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Build FIS
     let mut fis = FuzzyInferenceSystem::new("Wildcard Test");
@@ -343,7 +361,8 @@ fn test_duplicate_trapezoid_terms_should_error() {
             c: 60.0,
             d: 80.0,
         },
-    ));
+    ))
+    .unwrap();
 
     // Output variable with two identical terms
     let mut fan = LinguisticVariable::new(
@@ -361,7 +380,8 @@ fn test_duplicate_trapezoid_terms_should_error() {
             c: 6.0,
             d: 8.0,
         },
-    ));
+    ))
+    .unwrap();
     let add_term_result = fan.add_term(Term::new(
         "normal",
         M::Trapezoid {
@@ -384,3 +404,775 @@ fn test_duplicate_trapezoid_terms_should_error() {
         }
     }
 }
+
+#[test]
+fn test_sugeno_zero_order_weighted_average() {
+    let mut service = LinguisticVariable::new(
+        "service",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    service
+        .add_term(Term::new(
+            "poor",
+            M::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "excellent",
+            M::Triangle {
+                a: 0.0,
+                b: 10.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+
+    let mut tip = LinguisticVariable::new(
+        "tip",
+        Range {
+            min: 0.0,
+            max: 30.0,
+        },
+    );
+    tip.add_term(Term::new("cheap", M::Constant { value: 5.0 }))
+        .unwrap();
+    tip.add_term(Term::new("generous", M::Constant { value: 25.0 }))
+        .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Sugeno tip");
+    fis.add_input(service);
+    fis.add_output(tip);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("poor".into())],
+            vec!["cheap".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("excellent".into())],
+            vec!["generous".into()],
+            Connective::And,
+        ),
+    ]);
+
+    // service = 7.5 -> poor 0.25, excellent 0.75 -> (0.25*5 + 0.75*25) / 1.0 = 20
+    let result = fis.compute(FisType::Sugeno, &[7.5]).unwrap();
+    assert!((result[0] - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_sugeno_first_order_linear_consequent() {
+    let mut x = LinguisticVariable::new(
+        "x",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    x.add_term(Term::new(
+        "any",
+        M::Trapezoid {
+            a: -1.0,
+            b: 0.0,
+            c: 10.0,
+            d: 11.0,
+        },
+    ))
+    .unwrap();
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+    );
+    y.add_term(Term::new(
+        "line",
+        M::Linear {
+            coefficients: vec![2.0],
+            constant: 1.0,
+        },
+    ))
+    .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Sugeno linear");
+    fis.add_input(x);
+    fis.add_output(y);
+    fis.set_rules(vec![Rule::new(
+        vec![Some("any".into())],
+        vec!["line".into()],
+        Connective::And,
+    )]);
+
+    // Single fully fired rule reproduces the linear consequent: 1 + 2 * 4 = 9
+    let result = fis.compute(FisType::Sugeno, &[4.0]).unwrap();
+    assert!((result[0] - 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_sugeno_rejects_non_sugeno_consequent() {
+    let mut x = LinguisticVariable::new(
+        "x",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    x.add_term(Term::new(
+        "low",
+        M::Triangle {
+            a: 0.0,
+            b: 0.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    y.add_term(Term::new(
+        "small",
+        M::Triangle {
+            a: 0.0,
+            b: 0.0,
+            c: 5.0,
+        },
+    ))
+    .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("Sugeno invalid");
+    fis.add_input(x);
+    fis.add_output(y);
+    fis.set_rules(vec![Rule::new(
+        vec![Some("low".into())],
+        vec!["small".into()],
+        Connective::And,
+    )]);
+
+    match fis.compute(FisType::Sugeno, &[2.0]) {
+        Err(FisError::InvalidSugenoTerm(name)) => assert_eq!(name, "small"),
+        other => panic!("Expected FisError::InvalidSugenoTerm, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_tsukamoto_weighted_average_of_inverted_consequents() {
+    let mut budget = LinguisticVariable::new(
+        "budget",
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+    );
+    budget
+        .add_term(Term::new(
+            "low",
+            M::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 100.0,
+            },
+        ))
+        .unwrap();
+    budget
+        .add_term(Term::new(
+            "high",
+            M::Triangle {
+                a: 0.0,
+                b: 100.0,
+                c: 100.0,
+            },
+        ))
+        .unwrap();
+
+    // Monotonic consequents: "buy" falls, "build" rises over the whole range
+    let mut decision = LinguisticVariable::new(
+        "decision",
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+    );
+    decision
+        .add_term(Term::new(
+            "buy",
+            M::Trapezoid {
+                a: 0.0,
+                b: 0.0,
+                c: 0.0,
+                d: 100.0,
+            },
+        ))
+        .unwrap();
+    decision
+        .add_term(Term::new(
+            "build",
+            M::Triangle {
+                a: 0.0,
+                b: 100.0,
+                c: 100.0,
+            },
+        ))
+        .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Tsukamoto build vs buy");
+    fis.add_input(budget);
+    fis.add_output(decision);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("low".into())],
+            vec!["buy".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("high".into())],
+            vec!["build".into()],
+            Connective::And,
+        ),
+    ]);
+
+    // budget = 75 -> low 0.25 (buy z = 75), high 0.75 (build z = 75) -> 75
+    let result = fis.compute(FisType::Tsukamoto, &[75.0]).unwrap();
+    assert!((result[0] - 75.0).abs() < 1e-9);
+
+    // budget = 20 -> low 0.8 (z = 20), high 0.2 (z = 20) -> 20
+    let result = fis.compute(FisType::Tsukamoto, &[20.0]).unwrap();
+    assert!((result[0] - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_tsukamoto_inverts_smooth_monotonic_consequents() {
+    // temperature = 33.75 -> cold 0, hot 0.75, inverted on the "low" consequent
+    let cases = [
+        // c + ln(0.75 / 0.25) / a
+        (M::Sigmoid { a: 0.2, c: 50.0 }, 50.0 + 3.0_f64.ln() / 0.2),
+        // upper half: b - (b - a) * sqrt((1 - 0.75) / 2)
+        (
+            M::SShape { a: 20.0, b: 80.0 },
+            80.0 - 60.0 * 0.125_f64.sqrt(),
+        ),
+        // mirrored S at 0.25: a + (b - a) * sqrt(0.25 / 2)
+        (
+            M::ZShape { a: 20.0, b: 80.0 },
+            20.0 + 60.0 * 0.125_f64.sqrt(),
+        ),
+    ];
+    for (low, expected) in cases {
+        let fis = heater(low, M::RightShoulder { a: 0.0, b: 100.0 });
+        let result = fis.compute(FisType::Tsukamoto, &[33.75]).unwrap();
+        assert!(
+            (result[0] - expected).abs() < 1e-9,
+            "{:?}: {} vs {}",
+            fis.outputs[0].terms[0].kind,
+            result[0],
+            expected
+        );
+        let compiled = fis.compile(FisType::Tsukamoto).unwrap();
+        assert_eq!(compiled.compute(&[33.75]).unwrap(), result);
+    }
+}
+
+#[test]
+fn test_tsukamoto_rejects_non_monotonic_consequent() {
+    let mut x = LinguisticVariable::new(
+        "x",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    x.add_term(Term::new(
+        "low",
+        M::Triangle {
+            a: 0.0,
+            b: 0.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    y.add_term(Term::new(
+        "neutral",
+        M::Triangle {
+            a: 2.0,
+            b: 5.0,
+            c: 8.0,
+        },
+    ))
+    .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("Tsukamoto invalid");
+    fis.add_input(x);
+    fis.add_output(y);
+    fis.set_rules(vec![Rule::new(
+        vec![Some("low".into())],
+        vec!["neutral".into()],
+        Connective::And,
+    )]);
+
+    match fis.compute(FisType::Tsukamoto, &[2.0]) {
+        Err(FisError::NonMonotonicTerm(name)) => assert_eq!(name, "neutral"),
+        other => panic!("Expected FisError::NonMonotonicTerm, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rule_weight_scales_firing_strength() {
+    let mut service = LinguisticVariable::new(
+        "service",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    service
+        .add_term(Term::new(
+            "poor",
+            M::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "excellent",
+            M::Triangle {
+                a: 0.0,
+                b: 10.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+
+    let mut tip = LinguisticVariable::new(
+        "tip",
+        Range {
+            min: 0.0,
+            max: 30.0,
+        },
+    );
+    tip.add_term(Term::new("cheap", M::Constant { value: 5.0 }))
+        .unwrap();
+    tip.add_term(Term::new("generous", M::Constant { value: 25.0 }))
+        .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Weighted tip");
+    fis.add_input(service);
+    fis.add_output(tip);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("poor".into())],
+            vec!["cheap".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("excellent".into())],
+            vec!["generous".into()],
+            Connective::And,
+        )
+        .with_weight(0.5)
+        .unwrap(),
+    ]);
+
+    // service = 5 -> poor 0.5, excellent 0.5 * 0.5 = 0.25 -> (2.5 + 6.25) / 0.75
+    let result = fis.compute(FisType::Sugeno, &[5.0]).unwrap();
+    assert!((result[0] - 8.75 / 0.75).abs() < 1e-9);
+
+    // Weights set directly on the public field are checked at compute time
+    fis.rules[1].weight = 2.0;
+    match fis.compute(FisType::Sugeno, &[5.0]) {
+        Err(FisError::InvalidRuleWeight(w)) => assert_eq!(w, 2.0),
+        other => panic!("Expected FisError::InvalidRuleWeight, got {:?}", other),
+    }
+}
+
+fn four_input_system() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("Expression");
+    for name in ["a", "b", "c", "d"] {
+        let mut var = LinguisticVariable::new(
+            name,
+            Range {
+                min: 0.0,
+                max: 10.0,
+            },
+        );
+        var.add_term(Term::new(
+            "high",
+            M::Triangle {
+                a: 0.0,
+                b: 10.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+        fis.add_input(var);
+    }
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    y.add_term(Term::new("on", M::Constant { value: 10.0 }))
+        .unwrap();
+    y.add_term(Term::new("off", M::Constant { value: 0.0 }))
+        .unwrap();
+    fis.add_output(y);
+    fis
+}
+
+#[test]
+fn test_nested_expression_rule() {
+    let mut fis = four_input_system();
+    // IF (a IS high AND b IS high) OR (c IS high AND NOT d IS high) THEN y IS on
+    // IF <always> THEN y IS off
+    fis.set_rules(vec![
+        Rule::from_expression(
+            Expression::or(vec![
+                Expression::and(vec![
+                    Expression::is("a", "high"),
+                    Expression::is("b", "high"),
+                ]),
+                Expression::and(vec![
+                    Expression::is("c", "high"),
+                    !Expression::is("d", "high"),
+                ]),
+            ]),
+            vec!["on".into()],
+        ),
+        Rule::from_expression(Expression::and(vec![]), vec!["off".into()]),
+    ]);
+
+    // a 0.2, b 0.9 -> 0.2 ; c 0.8, NOT d (0.4) 0.6 -> 0.6 ; OR -> 0.6
+    let result = fis.compute(FisType::Sugeno, &[2.0, 9.0, 8.0, 4.0]).unwrap();
+    assert!((result[0] - 10.0 * 0.6 / 1.6).abs() < 1e-9);
+}
+
+#[test]
+fn test_expression_matches_positional_rule() {
+    let mut positional = four_input_system();
+    positional.set_rules(vec![Rule::new(
+        vec![Some("high".into()), None, Some("high".into()), None],
+        vec!["on".into()],
+        Connective::And,
+    )
+    .negate(2)]);
+
+    let mut expression = four_input_system();
+    expression.set_rules(vec![Rule::from_expression(
+        Expression::and(vec![
+            Expression::is("a", "high"),
+            !Expression::is("c", "high"),
+        ]),
+        vec!["on".into()],
+    )]);
+
+    let inputs = [7.0, 1.0, 3.0, 5.0];
+    let a = positional.compute(FisType::Mamdani, &inputs).unwrap();
+    let b = expression.compute(FisType::Mamdani, &inputs).unwrap();
+    assert!((a[0] - b[0]).abs() < 1e-12);
+}
+
+#[test]
+fn test_expression_with_unknown_variable() {
+    let mut fis = four_input_system();
+    fis.set_rules(vec![Rule::from_expression(
+        Expression::is("e", "high"),
+        vec!["on".into()],
+    )]);
+
+    match fis.compute(FisType::Sugeno, &[0.0, 0.0, 0.0, 0.0]) {
+        Err(FisError::VariableNotFound(name)) => assert_eq!(name, "e"),
+        other => panic!("Expected FisError::VariableNotFound, got {:?}", other),
+    }
+}
+
+// One input and one output with two rules, the output terms as given
+fn heater(low: M, high: M) -> FuzzyInferenceSystem {
+    let mut temperature = LinguisticVariable::new(
+        "temperature",
+        Range {
+            min: 0.0,
+            max: 40.0,
+        },
+    );
+    temperature
+        .add_term(Term::new(
+            "cold",
+            M::Trapezoid {
+                a: 0.0,
+                b: 0.0,
+                c: 10.0,
+                d: 25.0,
+            },
+        ))
+        .unwrap();
+    temperature
+        .add_term(Term::new(
+            "hot",
+            M::Triangle {
+                a: 15.0,
+                b: 40.0,
+                c: 40.0,
+            },
+        ))
+        .unwrap();
+    let mut power = LinguisticVariable::new(
+        "power",
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+    );
+    power.add_term(Term::new("low", low)).unwrap();
+    power.add_term(Term::new("high", high)).unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("heater");
+    fis.add_input(temperature);
+    fis.add_output(power);
+    fis.add_rule_str("IF temperature IS cold THEN power IS high")
+        .unwrap();
+    fis.add_rule_str("IF temperature IS hot THEN power IS low")
+        .unwrap();
+    fis
+}
+
+#[test]
+fn test_piecewise_linear_centroid_does_not_depend_on_resolution() {
+    let low = M::Triangle {
+        a: 0.0,
+        b: 0.0,
+        c: 60.0,
+    };
+    let high = M::Trapezoid {
+        a: 40.0,
+        b: 70.0,
+        c: 100.0,
+        d: 100.0,
+    };
+    let mut coarse = heater(low.clone(), high.clone());
+    coarse.resolution = 7;
+    let mut fine = heater(low.clone(), high);
+    fine.resolution = 5000;
+
+    for t in [0.0, 12.5, 20.0, 27.0, 40.0] {
+        let a = coarse.compute(FisType::Mamdani, &[t]).unwrap()[0];
+        let b = fine.compute(FisType::Mamdani, &[t]).unwrap()[0];
+        assert!((a - b).abs() < 1e-9, "temperature {}: {} vs {}", t, a, b);
+    }
+
+    // a Gauss consequent falls back to sampling the output axis
+    let gauss = M::Gauss {
+        sigma: 15.0,
+        mu: 100.0,
+    };
+    let mut coarse = heater(low.clone(), gauss.clone());
+    coarse.resolution = 7;
+    let fine = heater(low, gauss);
+    let a = coarse.compute(FisType::Mamdani, &[20.0]).unwrap()[0];
+    let b = fine.compute(FisType::Mamdani, &[20.0]).unwrap()[0];
+    assert!((a - b).abs() > 0.1);
+}
+
+#[test]
+fn test_shoulders_cover_inputs_beyond_the_range() {
+    let low = M::LeftShoulder { a: 0.0, b: 60.0 };
+    let high = M::RightShoulder { a: 40.0, b: 70.0 };
+
+    // bounded input terms: nothing fires below the range
+    let bounded = heater(low.clone(), high.clone());
+    let inside = bounded.compute(FisType::Mamdani, &[0.0]).unwrap()[0];
+    let below = bounded.compute(FisType::Mamdani, &[-10.0]).unwrap()[0];
+    assert!((inside - below).abs() > 10.0);
+
+    let mut fis = heater(low, high);
+    fis.inputs[0].terms[0].kind = M::LeftShoulder { a: 10.0, b: 25.0 };
+    fis.inputs[0].terms[1].kind = M::RightShoulder { a: 15.0, b: 40.0 };
+    assert!(fis.validate().is_empty());
+
+    let at_min = fis.compute(FisType::Mamdani, &[0.0]).unwrap()[0];
+    let at_max = fis.compute(FisType::Mamdani, &[40.0]).unwrap()[0];
+    assert!(at_min > 70.0);
+    assert!(at_max < 30.0);
+    assert_eq!(fis.compute(FisType::Mamdani, &[-10.0]).unwrap()[0], at_min);
+    assert_eq!(fis.compute(FisType::Mamdani, &[55.0]).unwrap()[0], at_max);
+
+    // open-ended consequents are monotonic, so the model also runs as Tsukamoto
+    let z = fis.compute(FisType::Tsukamoto, &[-10.0]).unwrap()[0];
+    assert!((z - 70.0).abs() < 1e-9);
+}
+
+// logistic curve, practically saturated 25 above its midpoint
+#[derive(Debug)]
+struct Logistic {
+    midpoint: f64,
+    scale: f64,
+}
+
+impl Membership for Logistic {
+    fn degree(&self, x: f64) -> f64 {
+        1.0 / (1.0 + (-(x - self.midpoint) / self.scale).exp())
+    }
+
+    fn support(&self) -> Range {
+        Range {
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+        }
+    }
+
+    fn core(&self) -> Range {
+        Range {
+            min: self.midpoint + 5.0 * self.scale,
+            max: f64::INFINITY,
+        }
+    }
+}
+
+#[test]
+fn test_custom_membership_in_rules() {
+    let low = M::LeftShoulder { a: 0.0, b: 60.0 };
+    let high = M::custom(Logistic {
+        midpoint: 70.0,
+        scale: 5.0,
+    });
+    let fis = heater(low, high);
+    assert!(fis.validate().is_empty());
+
+    // only "cold" fires: the centroid of the logistic tail over the output range
+    let cold = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!(cold > 75.0 && cold < 90.0);
+    let hot = fis.compute(FisType::Mamdani, &[40.0]).unwrap()[0];
+    assert!(hot < 30.0);
+
+    let compiled = fis.compile(FisType::Mamdani).unwrap();
+    assert_eq!(compiled.compute(&[5.0]).unwrap()[0], cold);
+
+    // no inverse, so not a Tsukamoto consequent
+    assert!(matches!(
+        fis.compute(FisType::Tsukamoto, &[5.0]),
+        Err(FisError::NonMonotonicTerm(_))
+    ));
+    assert!(matches!(
+        fis.compile(FisType::Tsukamoto),
+        Err(FisError::NonMonotonicTerm(_))
+    ));
+
+    // weighted average uses the finite end of the open core
+    let mut fis = fis;
+    fis.set_defuzzifier(Defuzzifier::WeightedAverage);
+    assert_eq!(fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0], 95.0);
+}
+
+#[test]
+fn test_singleton_centroid_does_not_depend_on_resolution() {
+    let low = M::Singleton { value: 20.0 };
+    let high = M::Singleton { value: 90.0 };
+    let mut coarse = heater(low.clone(), high.clone());
+    coarse.resolution = 7;
+    let fine = heater(low, high);
+
+    // cold 0.25, hot 0.25: midway between the two points
+    let c = coarse.compute(FisType::Mamdani, &[21.25]).unwrap()[0];
+    assert!((c - 55.0).abs() < 1e-9);
+    for t in [0.0, 12.5, 20.0, 27.0, 40.0] {
+        let a = coarse.compute(FisType::Mamdani, &[t]).unwrap()[0];
+        let b = fine.compute(FisType::Mamdani, &[t]).unwrap()[0];
+        assert!((a - b).abs() < 1e-9, "temperature {}: {} vs {}", t, a, b);
+    }
+}
+
+#[test]
+fn test_wildcard_is_neutral_in_or_rules() {
+    let range = Range {
+        min: 0.0,
+        max: 10.0,
+    };
+    let lo = M::LeftShoulder { a: 0.0, b: 10.0 };
+    let hi = M::RightShoulder { a: 0.0, b: 10.0 };
+    let mut fis = FuzzyInferenceSystem::new("wildcards");
+    for name in ["a", "b"] {
+        let mut input = LinguisticVariable::new(name, range);
+        input.add_term(Term::new("lo", lo.clone())).unwrap();
+        input.add_term(Term::new("hi", hi.clone())).unwrap();
+        fis.add_input(input);
+    }
+    let mut out = LinguisticVariable::new("out", range);
+    out.add_term(Term::new("low", M::Constant { value: 0.0 }))
+        .unwrap();
+    out.add_term(Term::new("high", M::Constant { value: 10.0 }))
+        .unwrap();
+    fis.add_output(out);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("lo".into()), None],
+            vec!["high".into()],
+            Connective::Or,
+        ),
+        Rule::new(
+            vec![Some("hi".into()), None],
+            vec!["low".into()],
+            Connective::And,
+        ),
+    ]);
+
+    // a = 10: the OR rule fires lo = 0, the AND rule hi = 1
+    let result = fis.compute(FisType::Sugeno, &[10.0, 3.0]).unwrap();
+    assert_eq!(result, vec![0.0]);
+    let compiled = fis.compile(FisType::Sugeno).unwrap();
+    assert_eq!(compiled.compute(&[10.0, 3.0]).unwrap(), result);
+
+    let fixed = FixedFis::<2, 1, 2, 2, 1>::new(
+        [
+            FixedVariable::new(range, [Some(lo.clone()), Some(hi.clone())]),
+            FixedVariable::new(range, [Some(lo), Some(hi)]),
+        ],
+        [FixedVariable::new(
+            range,
+            [
+                Some(M::Constant { value: 0.0 }),
+                Some(M::Constant { value: 10.0 }),
+            ],
+        )],
+        [
+            FixedRule::new([Some(0), None], [Some(1)], Connective::Or),
+            FixedRule::new([Some(1), None], [Some(0)], Connective::And),
+        ],
+    );
+    assert_eq!(
+        fixed
+            .compute(FisType::Sugeno, &[10.0, 3.0])
+            .unwrap()
+            .to_vec(),
+        result
+    );
+
+    // a = 4: lo 0.6 and hi 0.4
+    let result = fis.compute(FisType::Sugeno, &[4.0, 3.0]).unwrap()[0];
+    assert!((result - 6.0).abs() < 1e-9);
+}
@@ -0,0 +1,391 @@
+mod common;
+
+use std::cell::Cell;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub};
+
+use common::{
+    gas_burner::{self, BURNER},
+    restaurant_tip_level, with_float,
+};
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    fixed::{FixedFis, FixedRule, FixedVariable},
+    fixed_point::Q16,
+    float::Float,
+    membership::MembershipKind,
+    operators::{Complement, SNorm, TNorm},
+    rule::Connective,
+    variable::{LinguisticVariable, Range},
+};
+
+const STEP: f64 = 1.0 / 65536.0;
+
+fn q(x: f64) -> Q16 {
+    Q16::from_f64(x)
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(Q16::ONE.to_bits(), 65536);
+    assert_eq!(q(1.5).to_bits(), 98304);
+    assert_eq!(q(-0.25).to_bits(), -16384);
+    assert_eq!(q(0.1).to_f64(), 6554.0 / 65536.0);
+    assert_eq!(Q16::from_usize(200), q(200.0));
+    assert_eq!(q(1e9), Q16::MAX);
+    assert_eq!(q(-1e9), Q16::MIN);
+    assert_eq!(q(f64::NAN), Q16::ZERO);
+    assert_eq!(format!("{}", q(17.5)), "17.5");
+    assert_eq!(format!("{:?}", [q(-2.0)]), "[-2]");
+}
+
+#[test]
+fn test_arithmetic_rounds_and_saturates() {
+    assert_eq!(q(1.5) + q(2.25), q(3.75));
+    assert_eq!(q(1.5) - q(2.25), q(-0.75));
+    assert_eq!(q(1.5) * q(-2.25), q(-3.375));
+    assert_eq!(q(1.0) / q(3.0), Q16::from_bits(21845));
+    assert_eq!(q(2.0) / q(3.0), Q16::from_bits(43691));
+    assert_eq!(q(-2.0) / q(3.0), Q16::from_bits(-43691));
+
+    assert_eq!(q(30000.0) + q(30000.0), Q16::MAX);
+    assert_eq!(q(-30000.0) - q(30000.0), Q16::MIN);
+    assert_eq!(q(300.0) * q(-300.0), Q16::MIN);
+    assert_eq!(-Q16::MIN, Q16::MAX);
+    assert_eq!(q(5.0) / Q16::ZERO, Q16::INFINITY);
+    assert_eq!(q(-5.0) / Q16::ZERO, Q16::NEG_INFINITY);
+    assert_eq!(Q16::ZERO / Q16::ZERO, Q16::ZERO);
+}
+
+#[test]
+fn test_exp_ln_and_powf_match_f64() {
+    for i in -120..=100 {
+        let x = i as f64 / 10.0;
+        let expected = x.exp().min(Q16::MAX.to_f64());
+        let actual = q(x).exp().to_f64();
+        // a couple of steps, relative above 1
+        assert!(
+            (actual - expected).abs() <= 2.0 * STEP * expected.max(1.0),
+            "exp({}) = {} vs {}",
+            x,
+            actual,
+            expected
+        );
+    }
+    assert_eq!(q(11.0).exp(), Q16::MAX);
+    assert_eq!(q(-30000.0).exp(), Q16::ZERO);
+
+    for base in [0.01, 0.1, 0.25, 0.5, 0.9, 1.0, 2.0, 10.0] {
+        for n in [0.5, 1.0, 2.0, 3.0, 1.0 / 3.0, -1.0] {
+            let expected = f64::powf(base, n);
+            let actual = q(base).powf(q(n)).to_f64();
+            // the base is rounded to a step first
+            let tolerance = 4.0 * STEP * expected.max(1.0) + (expected * n / base).abs() * STEP;
+            assert!(
+                (actual - expected).abs() <= tolerance,
+                "{}^{} = {} vs {}",
+                base,
+                n,
+                actual,
+                expected
+            );
+        }
+    }
+    for x in [0.001, 0.1, 0.5, 1.0, 2.0, 10.0, 1000.0, 30000.0] {
+        let actual = q(x).ln().to_f64();
+        // the argument is rounded to a step first
+        let tolerance = 2.0 * STEP + STEP / x;
+        assert!(
+            (actual - x.ln()).abs() <= tolerance,
+            "ln({}) = {} vs {}",
+            x,
+            actual,
+            x.ln()
+        );
+    }
+    assert_eq!(Q16::ZERO.ln(), Q16::NEG_INFINITY);
+
+    assert_eq!(Q16::ZERO.powf(q(2.0)), Q16::ZERO);
+    assert_eq!(q(0.3).powf(Q16::ZERO), Q16::ONE);
+}
+
+fn assert_agrees(
+    fixed: &FuzzyInferenceSystem<Q16>,
+    double: &FuzzyInferenceSystem,
+    fis_type: FisType,
+) {
+    let grid = |var: &LinguisticVariable| {
+        (0..=10)
+            .map(|i| var.range.min + (var.range.max - var.range.min) * i as f64 / 10.0)
+            .collect::<Vec<f64>>()
+    };
+    let rows: Vec<Vec<f64>> = double.inputs.iter().fold(vec![Vec::new()], |rows, var| {
+        rows.iter()
+            .flat_map(|row| {
+                grid(var).into_iter().map(move |x| {
+                    let mut row = row.clone();
+                    row.push(x);
+                    row
+                })
+            })
+            .collect()
+    });
+    for row in rows {
+        let inputs: Vec<Q16> = row.iter().map(|x| q(*x)).collect();
+        let out_q = fixed.compute(fis_type, &inputs).unwrap();
+        let out_f = double.compute(fis_type, &row).unwrap();
+        for ((x_q, x_f), var) in out_q.iter().zip(&out_f).zip(&double.outputs) {
+            // within 0.1% of the output range
+            let tolerance = 1e-3 * (var.range.max - var.range.min);
+            assert!(
+                (x_q.to_f64() - x_f).abs() <= tolerance,
+                "{} {:?} at {:?}: Q16 {} vs f64 {}",
+                double.name,
+                double.defuzzifier,
+                row,
+                x_q,
+                x_f
+            );
+        }
+    }
+}
+
+#[test]
+fn test_example_systems_agree_with_f64() {
+    let fis_type = FisType::Mamdani;
+    // (the motor control centroid sums reach thousands squared, beyond the Q16.16 range)
+    for mut double in [
+        gas_burner::system().unwrap(),
+        restaurant_tip_level::system().unwrap(),
+    ] {
+        let mut fixed = with_float::<Q16>(&double);
+        assert_agrees(&fixed, &double, fis_type);
+
+        fixed.set_defuzzifier(Defuzzifier::MeanOfMaximum);
+        double.set_defuzzifier(Defuzzifier::MeanOfMaximum);
+        assert_agrees(&fixed, &double, fis_type);
+
+        fixed.set_defuzzifier(Defuzzifier::WeightedAverage);
+        double.set_defuzzifier(Defuzzifier::WeightedAverage);
+        fixed.set_or_method(SNorm::ProbabilisticSum);
+        double.set_or_method(SNorm::ProbabilisticSum);
+        fixed.set_complement(Complement::Yager(Q16::TWO));
+        double.set_complement(Complement::Yager(2.0));
+        assert_agrees(&fixed, &double, fis_type);
+    }
+}
+
+#[test]
+fn test_gas_burner_result_holds() {
+    let burner = with_float::<Q16>(&gas_burner::system().unwrap());
+    let result = burner.compute(FisType::Mamdani, &[q(0.51)]).unwrap();
+    assert!(result[0] > q(20.42) && result[0] < q(20.44));
+
+    let compiled = burner.compile(FisType::Mamdani).unwrap();
+    assert_eq!(compiled.compute(&[q(0.51)]).unwrap(), result);
+}
+
+#[test]
+fn test_fixed_engine_in_q16() {
+    let double = gas_burner::system().unwrap();
+    for i in 0..=30 {
+        let oxi = i as f64 / 10.0;
+        let [power] = BURNER.compute(FisType::Mamdani, &[q(oxi)]).unwrap();
+        let expected = double.compute(FisType::Mamdani, &[oxi]).unwrap()[0];
+        assert!(
+            (power.to_f64() - expected).abs() < 0.1,
+            "OXI {}: Q16 {} vs f64 {}",
+            oxi,
+            power,
+            expected
+        );
+    }
+}
+
+// Q16 counting the float conversions made through the `Float` trait
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd, Debug)]
+struct Counted(Q16);
+
+thread_local! {
+    static CONVERSIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn conversions() -> usize {
+    CONVERSIONS.with(Cell::take)
+}
+
+impl fmt::Display for Counted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! forward_binary {
+    ($($trait:ident $method:ident),*) => {$(
+        impl $trait for Counted {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                Counted(self.0.$method(other.0))
+            }
+        }
+    )*};
+}
+forward_binary!(Add add, Sub sub, Mul mul, Div div);
+
+impl Neg for Counted {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Counted(-self.0)
+    }
+}
+
+impl AddAssign for Counted {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl DivAssign for Counted {
+    fn div_assign(&mut self, other: Self) {
+        self.0 /= other.0;
+    }
+}
+
+impl Float for Counted {
+    const ZERO: Self = Counted(Q16::ZERO);
+    const ONE: Self = Counted(Q16::ONE);
+    const TWO: Self = Counted(Q16::TWO);
+    const HALF: Self = Counted(Q16::HALF);
+    const EPSILON: Self = Counted(Q16::EPSILON);
+    const INFINITY: Self = Counted(Q16::INFINITY);
+    const NEG_INFINITY: Self = Counted(Q16::NEG_INFINITY);
+
+    fn from_f64(x: f64) -> Self {
+        CONVERSIONS.with(|n| n.set(n.get() + 1));
+        Counted(Q16::from_f64(x))
+    }
+
+    fn to_f64(self) -> f64 {
+        CONVERSIONS.with(|n| n.set(n.get() + 1));
+        self.0.to_f64()
+    }
+
+    fn from_usize(n: usize) -> Self {
+        Counted(Q16::from_usize(n))
+    }
+
+    fn to_usize(self) -> usize {
+        self.0.to_usize()
+    }
+
+    fn exp(self) -> Self {
+        Counted(self.0.exp())
+    }
+
+    fn ln(self) -> Self {
+        Counted(self.0.ln())
+    }
+
+    fn powf(self, n: Self) -> Self {
+        Counted(self.0.powf(n.0))
+    }
+}
+
+fn c(x: f64) -> Counted {
+    Counted(q(x))
+}
+
+#[test]
+fn test_evaluation_makes_no_float_conversion() {
+    let tip = with_float::<Counted>(&restaurant_tip_level::system().unwrap());
+    let burner = with_float::<Counted>(&gas_burner::system().unwrap());
+    let fixed = FixedFis::<2, 1, 3, 3, 101, Counted>::new(
+        [
+            FixedVariable::new(
+                Range {
+                    min: c(0.0),
+                    max: c(10.0),
+                },
+                [
+                    Some(MembershipKind::Gauss {
+                        sigma: c(2.0),
+                        mu: c(0.0),
+                    }),
+                    Some(MembershipKind::Sigmoid {
+                        a: c(2.0),
+                        c: c(5.0),
+                    }),
+                    None,
+                ],
+            ),
+            FixedVariable::new(
+                Range {
+                    min: c(0.0),
+                    max: c(10.0),
+                },
+                [
+                    Some(MembershipKind::SShape {
+                        a: c(2.0),
+                        b: c(8.0),
+                    }),
+                    None,
+                    None,
+                ],
+            ),
+        ],
+        [FixedVariable::new(
+            Range {
+                min: c(0.0),
+                max: c(30.0),
+            },
+            [
+                Some(MembershipKind::Triangle {
+                    a: c(0.0),
+                    b: c(5.0),
+                    c: c(10.0),
+                }),
+                Some(MembershipKind::Gauss {
+                    sigma: c(3.0),
+                    mu: c(15.0),
+                }),
+                Some(MembershipKind::Trapezoid {
+                    a: c(20.0),
+                    b: c(25.0),
+                    c: c(30.0),
+                    d: c(30.0),
+                }),
+            ],
+        )],
+        [
+            FixedRule::new([Some(0), None], [Some(0)], Connective::And),
+            FixedRule::new([Some(1), Some(0)], [Some(1)], Connective::Or).with_weight(c(0.5)),
+            FixedRule::new([Some(1), Some(0)], [Some(2)], Connective::And).negate(1),
+        ],
+    )
+    .with_and_method(TNorm::Hamacher(c(0.5)))
+    .with_complement(Complement::Yager(c(2.0)));
+    let tip = tip.compile(FisType::Mamdani).unwrap();
+    let burner = burner.compile(FisType::Mamdani).unwrap();
+    // loading and building convert the f64 parameters
+    assert!(conversions() > 0);
+
+    let inputs = [c(3.0), c(7.5)];
+
+    let defuzzifiers = [
+        Defuzzifier::Centroid,
+        Defuzzifier::Bisector,
+        Defuzzifier::MeanOfMaximum,
+        Defuzzifier::WeightedAverage,
+    ];
+    for defuzzifier in defuzzifiers {
+        let fixed = fixed.clone().with_defuzzifier(defuzzifier);
+        fixed.compute(FisType::Mamdani, &inputs).unwrap();
+        let grid = fixed.grid();
+        fixed.compute_on(&grid, FisType::Mamdani, &inputs).unwrap();
+    }
+    // exact centroid of the piecewise linear terms, and of singletons
+    tip.compute(&inputs).unwrap();
+    burner.compute(&[c(1.2)]).unwrap();
+    assert_eq!(conversions(), 0);
+}
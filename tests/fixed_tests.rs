@@ -0,0 +1,327 @@
+mod common;
+
+use common::variable;
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    fixed::{FixedFis, FixedFisError, FixedRule, FixedVariable},
+    membership::MembershipKind,
+    operators::{Aggregation, Implication, TNorm},
+    rule::{Connective, Rule},
+    variable::Range,
+};
+
+const DRY: MembershipKind = MembershipKind::Trapezoid {
+    a: 0.0,
+    b: 0.0,
+    c: 20.0,
+    d: 40.0,
+};
+const MOIST: MembershipKind = MembershipKind::Triangle {
+    a: 30.0,
+    b: 50.0,
+    c: 70.0,
+};
+const WET: MembershipKind = MembershipKind::Trapezoid {
+    a: 60.0,
+    b: 80.0,
+    c: 100.0,
+    d: 100.0,
+};
+const COOL: MembershipKind = MembershipKind::Gauss {
+    sigma: 8.0,
+    mu: 10.0,
+};
+const HOT: MembershipKind = MembershipKind::Gauss {
+    sigma: 8.0,
+    mu: 35.0,
+};
+const SHORT: MembershipKind = MembershipKind::Triangle {
+    a: 0.0,
+    b: 0.0,
+    c: 30.0,
+};
+const LONG: MembershipKind = MembershipKind::Triangle {
+    a: 10.0,
+    b: 60.0,
+    c: 60.0,
+};
+
+static IRRIGATION: FixedFis<2, 1, 3, 3, 121> = FixedFis::new(
+    [
+        FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 100.0,
+            },
+            [Some(DRY), Some(MOIST), Some(WET)],
+        ),
+        FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 45.0,
+            },
+            [Some(COOL), Some(HOT), None],
+        ),
+    ],
+    [FixedVariable::new(
+        Range {
+            min: 0.0,
+            max: 60.0,
+        },
+        [Some(SHORT), Some(LONG), None],
+    )],
+    [
+        FixedRule::new([Some(0), Some(1)], [Some(1)], Connective::And),
+        FixedRule::new([Some(1), None], [Some(0)], Connective::And).with_weight(0.8),
+        FixedRule::new([Some(2), Some(1)], [Some(0)], Connective::Or).negate(1),
+    ],
+);
+
+// The same controller built from the Vec based model
+fn irrigation(output_terms: Vec<(&str, MembershipKind)>) -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("irrigation");
+    fis.add_input(variable(
+        "moisture",
+        0.0,
+        100.0,
+        vec![("dry", DRY), ("moist", MOIST), ("wet", WET)],
+    ));
+    fis.add_input(variable(
+        "temperature",
+        0.0,
+        45.0,
+        vec![("cool", COOL), ("hot", HOT)],
+    ));
+    fis.add_output(variable("duration", 0.0, 60.0, output_terms));
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("dry".into()), Some("hot".into())],
+            vec!["long".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("moist".into()), None],
+            vec!["short".into()],
+            Connective::And,
+        )
+        .with_weight(0.8)
+        .unwrap(),
+        Rule::new(
+            vec![Some("wet".into()), Some("hot".into())],
+            vec!["short".into()],
+            Connective::Or,
+        )
+        .negate(1),
+    ]);
+    fis.resolution = 121;
+    fis
+}
+
+const SAMPLES: [[f64; 2]; 5] = [
+    [5.0, 40.0],
+    [25.0, 30.0],
+    [50.0, 20.0],
+    [75.0, 12.0],
+    [100.0, 0.0],
+];
+
+#[test]
+fn test_static_mamdani_matches_vec_model() {
+    let fis = irrigation(vec![("short", SHORT), ("long", LONG)]);
+    for inputs in SAMPLES {
+        let [duration] = IRRIGATION.compute(FisType::Mamdani, &inputs).unwrap();
+        assert_eq!(
+            vec![duration],
+            fis.compute(FisType::Mamdani, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_operators_and_defuzzifier() {
+    let mut fis = irrigation(vec![("short", SHORT), ("long", LONG)]);
+    fis.set_and_method(TNorm::Product);
+    fis.set_implication(Implication::Product);
+    fis.set_aggregation(Aggregation::BoundedSum);
+    fis.set_defuzzifier(Defuzzifier::Bisector);
+    let fixed = IRRIGATION
+        .clone()
+        .with_and_method(TNorm::Product)
+        .with_implication(Implication::Product)
+        .with_aggregation(Aggregation::BoundedSum)
+        .with_defuzzifier(Defuzzifier::Bisector);
+
+    for inputs in SAMPLES {
+        assert_eq!(
+            fixed.compute(FisType::Mamdani, &inputs).unwrap().to_vec(),
+            fis.compute(FisType::Mamdani, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_precomputed_grid_matches_sampling() {
+    for defuzzifier in [
+        Defuzzifier::Centroid,
+        Defuzzifier::Bisector,
+        Defuzzifier::MeanOfMaximum,
+    ] {
+        let fixed = IRRIGATION
+            .clone()
+            .with_aggregation(Aggregation::ProbabilisticSum)
+            .with_defuzzifier(defuzzifier);
+        let grid = fixed.grid();
+        for inputs in SAMPLES {
+            assert_eq!(
+                fixed.compute_on(&grid, FisType::Mamdani, &inputs),
+                fixed.compute(FisType::Mamdani, &inputs)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sugeno_and_tsukamoto() {
+    let sugeno_terms = [
+        MembershipKind::Constant { value: 5.0 },
+        MembershipKind::Constant { value: 45.0 },
+    ];
+    let fis = irrigation(vec![
+        ("short", sugeno_terms[0].clone()),
+        ("long", sugeno_terms[1].clone()),
+    ]);
+    let fixed = FixedFis::<2, 1, 3, 3, 1>::new(
+        IRRIGATION.inputs().clone(),
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 60.0,
+            },
+            [
+                Some(sugeno_terms[0].clone()),
+                Some(sugeno_terms[1].clone()),
+                None,
+            ],
+        )],
+        *IRRIGATION.rules(),
+    );
+    for inputs in SAMPLES {
+        assert_eq!(
+            fixed.compute(FisType::Sugeno, &inputs).unwrap().to_vec(),
+            fis.compute(FisType::Sugeno, &inputs).unwrap()
+        );
+    }
+
+    let fis = irrigation(vec![("short", SHORT), ("long", LONG)]);
+    for inputs in SAMPLES {
+        assert_eq!(
+            IRRIGATION
+                .compute(FisType::Tsukamoto, &inputs)
+                .unwrap()
+                .to_vec(),
+            fis.compute(FisType::Tsukamoto, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_singleton_mamdani_matches_vec_model() {
+    let short = MembershipKind::Singleton { value: 5.0 };
+    let long = MembershipKind::Singleton { value: 45.0 };
+    let mut fis = irrigation(vec![("short", short.clone()), ("long", long.clone())]);
+    // a single grid point: singletons are not sampled
+    let fixed = FixedFis::<2, 1, 3, 3, 1>::new(
+        IRRIGATION.inputs().clone(),
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 60.0,
+            },
+            [Some(short), Some(long), None],
+        )],
+        *IRRIGATION.rules(),
+    );
+    fis.resolution = 1;
+    let compiled = fis.compile(FisType::Mamdani).unwrap();
+    for inputs in SAMPLES {
+        let [duration] = fixed.compute(FisType::Mamdani, &inputs).unwrap();
+        assert_eq!(
+            vec![duration],
+            fis.compute(FisType::Mamdani, &inputs).unwrap()
+        );
+        assert_eq!(vec![duration], compiled.compute(&inputs).unwrap());
+        assert!((5.0..=45.0).contains(&duration));
+    }
+}
+
+#[test]
+fn test_consequent_kind_errors() {
+    assert_eq!(
+        IRRIGATION.compute(FisType::Sugeno, &[50.0, 20.0]),
+        Err(FixedFisError::InvalidSugenoTerm { output: 0, term: 1 })
+    );
+
+    let fixed = FixedFis::<1, 1, 1, 1, 11>::new(
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 100.0,
+            },
+            [Some(DRY)],
+        )],
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 100.0,
+            },
+            [Some(MOIST)],
+        )],
+        [FixedRule::new([Some(0)], [Some(0)], Connective::And)],
+    );
+    assert_eq!(
+        fixed.compute(FisType::Tsukamoto, &[10.0]),
+        Err(FixedFisError::NonMonotonicTerm { output: 0, term: 0 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "rule antecedent refers to an undefined term")]
+fn test_undefined_term_slot_panics() {
+    let _ = FixedFis::<2, 1, 3, 1, 11>::new(
+        IRRIGATION.inputs().clone(),
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 60.0,
+            },
+            [Some(SHORT), None, None],
+        )],
+        [FixedRule::new([None, Some(2)], [Some(0)], Connective::And)],
+    );
+}
+
+#[test]
+fn test_invalid_weight_is_reported() {
+    let fixed = FixedFis::<1, 1, 1, 1, 11>::new(
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 100.0,
+            },
+            [Some(DRY)],
+        )],
+        [FixedVariable::new(
+            Range {
+                min: 0.0,
+                max: 60.0,
+            },
+            [Some(SHORT)],
+        )],
+        [FixedRule::new([None], [Some(0)], Connective::And).with_weight(1.5)],
+    );
+    assert_eq!(
+        fixed.compute(FisType::Mamdani, &[10.0]),
+        Err(FixedFisError::InvalidRuleWeight { rule: 0 })
+    );
+}
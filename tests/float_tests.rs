@@ -0,0 +1,240 @@
+mod common;
+
+use common::{gas_burner, restaurant_tip_level, with_float};
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    fixed::{FixedFis, FixedRule, FixedVariable},
+    float::Float,
+    membership::MembershipKind,
+    operators::{Complement, SNorm, TNorm},
+    rule::Connective,
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+// Evenly spread samples over every input range, as (f32, f64) rows
+fn samples(fis: &FuzzyInferenceSystem, per_input: usize) -> Vec<(Vec<f32>, Vec<f64>)> {
+    let mut rows = vec![Vec::new()];
+    for var in &fis.inputs {
+        let step = (var.range.max - var.range.min) / (per_input - 1) as f64;
+        rows = rows
+            .into_iter()
+            .flat_map(|row: Vec<f64>| {
+                (0..per_input).map(move |i| {
+                    let mut row = row.clone();
+                    row.push(var.range.min + step * i as f64);
+                    row
+                })
+            })
+            .collect();
+    }
+    rows.into_iter()
+        .map(|row| (row.iter().map(|x| *x as f32).collect(), row))
+        .collect()
+}
+
+fn defuzzifiers<F: Float>() -> [Defuzzifier<F>; 4] {
+    [
+        Defuzzifier::Centroid,
+        Defuzzifier::Bisector,
+        Defuzzifier::MeanOfMaximum,
+        Defuzzifier::WeightedAverage,
+    ]
+}
+
+#[test]
+fn test_example_systems_agree_in_f32_and_f64() {
+    let fis_type = FisType::Mamdani;
+    for mut double in common::example_systems() {
+        let mut single = with_float::<f32>(&double);
+
+        for (d32, d64) in defuzzifiers().into_iter().zip(defuzzifiers()) {
+            single.set_defuzzifier(d32);
+            double.set_defuzzifier(d64);
+            for (row32, row64) in samples(&double, 11) {
+                let out32 = single.compute(fis_type, &row32).unwrap();
+                let out64 = double.compute(fis_type, &row64).unwrap();
+                // within 0.1% of the output range
+                for ((x32, x64), var) in out32.iter().zip(&out64).zip(&double.outputs) {
+                    let tolerance = 1e-3 * (var.range.max - var.range.min);
+                    assert!(
+                        (x32.to_f64() - x64).abs() <= tolerance,
+                        "{} {:?} at {:?}: f32 {} vs f64 {}",
+                        double.name,
+                        double.defuzzifier,
+                        row64,
+                        x32,
+                        x64
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_example_results_hold_in_f32() {
+    let tip = with_float::<f32>(&restaurant_tip_level::system().unwrap());
+    let result = tip.compute(FisType::Mamdani, &[7.892, 7.41]).unwrap();
+    assert!(result[0] > 18.0 && result[0] < 19.0);
+
+    let burner = with_float::<f32>(&gas_burner::system().unwrap());
+    let result = burner.compute(FisType::Mamdani, &[0.51]).unwrap();
+    assert!(result[0] > 20.42 && result[0] < 20.44);
+
+    let compiled = burner.compile(FisType::Mamdani).unwrap();
+    assert_eq!(compiled.compute(&[0.51]).unwrap(), result);
+}
+
+fn sugeno_tipper<F: Float>() -> FuzzyInferenceSystem<F> {
+    let f = F::from_f64;
+    let mut service = LinguisticVariable::new(
+        "service",
+        Range {
+            min: f(0.0),
+            max: f(10.0),
+        },
+    );
+    service
+        .add_term(Term::new(
+            "poor",
+            MembershipKind::Trapezoid {
+                a: f(0.0),
+                b: f(0.0),
+                c: f(2.0),
+                d: f(8.0),
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "excellent",
+            MembershipKind::Gauss {
+                sigma: f(3.0),
+                mu: f(10.0),
+            },
+        ))
+        .unwrap();
+    let mut tip = LinguisticVariable::new(
+        "tip",
+        Range {
+            min: f(0.0),
+            max: f(30.0),
+        },
+    );
+    tip.add_term(Term::new(
+        "cheap",
+        MembershipKind::Constant { value: f(5.0) },
+    ))
+    .unwrap();
+    tip.add_term(Term::new(
+        "generous",
+        MembershipKind::Linear {
+            coefficients: vec![f(2.0)],
+            constant: f(5.0),
+        },
+    ))
+    .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("tip");
+    fis.add_input(service);
+    fis.add_output(tip);
+    fis.add_rule_str("IF service IS poor THEN tip IS cheap")
+        .unwrap();
+    fis.add_rule_str(
+        "IF service IS NOT poor OR service IS excellent THEN tip IS generous WITH 0.9",
+    )
+    .unwrap();
+    fis.set_and_method(TNorm::Hamacher(F::HALF));
+    fis.set_or_method(SNorm::Einstein);
+    fis.set_complement(Complement::Yager(F::TWO));
+    fis
+}
+
+#[test]
+fn test_sugeno_and_operators_agree_in_f32_and_f64() {
+    let single = sugeno_tipper::<f32>();
+    let double = sugeno_tipper::<f64>();
+
+    for i in 0..=20 {
+        let x = i as f64 / 2.0;
+        let out32 = single.compute(FisType::Sugeno, &[x as f32]).unwrap()[0];
+        let out64 = double.compute(FisType::Sugeno, &[x]).unwrap()[0];
+        assert!((out32.to_f64() - out64).abs() < 1e-4, "service {}", x);
+    }
+}
+
+static HEATER: FixedFis<1, 1, 2, 2, 101, f32> = FixedFis::new(
+    [FixedVariable::new(
+        Range {
+            min: 0.0,
+            max: 40.0,
+        },
+        [
+            Some(MembershipKind::Trapezoid {
+                a: 0.0,
+                b: 0.0,
+                c: 10.0,
+                d: 25.0,
+            }),
+            Some(MembershipKind::Triangle {
+                a: 15.0,
+                b: 40.0,
+                c: 40.0,
+            }),
+        ],
+    )],
+    [FixedVariable::new(
+        Range {
+            min: 0.0,
+            max: 100.0,
+        },
+        [
+            Some(MembershipKind::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 60.0,
+            }),
+            Some(MembershipKind::Triangle {
+                a: 40.0,
+                b: 100.0,
+                c: 100.0,
+            }),
+        ],
+    )],
+    [
+        FixedRule::new([Some(0)], [Some(1)], Connective::And),
+        FixedRule::new([Some(1)], [Some(0)], Connective::And),
+    ],
+);
+
+#[test]
+fn test_fixed_engine_in_f32() {
+    let (mut fis, _) = FuzzyInferenceSystem::from_fis(
+        "[System]
+Name='heater'
+[Input1]
+Name='temperature'
+Range=[0 40]
+MF1='low':'trapmf',[0 0 10 25]
+MF2='high':'trimf',[15 40 40]
+[Output1]
+Name='power'
+Range=[0 100]
+MF1='low':'trimf',[0 0 60]
+MF2='high':'trimf',[40 100 100]
+[Rules]
+1, 2 (1) : 1
+2, 1 (1) : 1
+",
+    )
+    .unwrap();
+    fis.resolution = 101;
+
+    for t in 0..=40 {
+        let [single] = HEATER.compute(FisType::Mamdani, &[t as f32]).unwrap();
+        let double = fis.compute(FisType::Mamdani, &[t as f64]).unwrap()[0];
+        assert!((single as f64 - double).abs() < 0.1, "temperature {}", t);
+    }
+}
@@ -1,8 +1,17 @@
-use fuzzy_logic_engine_rs::math_helpers::{centroid, linspace};
+use fuzzy_logic_engine_rs::{
+    math_helpers::{
+        are_singletons, bisector, centroid, centroid_knots, is_piecewise_linear,
+        largest_of_maximum, linspace, mean_of_maximum, piecewise_linear_centroid,
+        singleton_centroid, smallest_of_maximum,
+    },
+    membership::MembershipKind,
+    operators::{Aggregation, Implication},
+    variable::Range,
+};
 
 #[test]
 fn test_linspace_generates_correct_number_of_points() {
-    let xs = linspace(0.0, 10.0, 6);
+    let xs: Vec<f64> = linspace(0.0, 10.0, 6);
     assert_eq!(xs.len(), 6);
     assert!((xs[0] - 0.0).abs() < 1e-6);
     assert!((xs[5] - 10.0).abs() < 1e-6);
@@ -25,7 +34,7 @@ fn test_linspace_with_one_point_returns_min() {
 
 #[test]
 fn test_centroid_of_symmetric_distribution_is_center() {
-    let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let xs: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0, 4.0];
     let mus = vec![0.0, 1.0, 2.0, 1.0, 0.0]; // symmetric around 2.0
     let c = centroid(&xs, &mus);
     assert!((c - 2.0).abs() < 1e-6);
@@ -33,7 +42,7 @@ fn test_centroid_of_symmetric_distribution_is_center() {
 
 #[test]
 fn test_centroid_with_uniform_distribution_is_average() {
-    let xs = vec![0.0, 1.0, 2.0, 3.0];
+    let xs: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0];
     let mus = vec![1.0, 1.0, 1.0, 1.0]; // flat membership
     let c = centroid(&xs, &mus);
     assert!((c - 1.5).abs() < 1e-6);
@@ -46,3 +55,248 @@ fn test_centroid_with_zero_membership_returns_midpoint() {
     let c = centroid(&xs, &mus);
     assert_eq!(c, 2.0); // fallback: middle element
 }
+
+#[test]
+fn test_bisector_splits_area_in_half() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let mus = vec![1.0, 1.0, 1.0, 1.0, 1.0];
+    assert_eq!(bisector(&xs, &mus), 2.0);
+
+    // Skewed to the right: half of the area is reached later
+    let mus = vec![0.0, 0.0, 1.0, 1.0, 1.0];
+    assert_eq!(bisector(&xs, &mus), 3.0);
+}
+
+#[test]
+fn test_maximum_based_methods() {
+    let xs: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let mus = vec![0.0, 0.5, 1.0, 1.0, 1.0, 0.2];
+    assert_eq!(smallest_of_maximum(&xs, &mus), 2.0);
+    assert_eq!(largest_of_maximum(&xs, &mus), 4.0);
+    assert!((mean_of_maximum(&xs, &mus) - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_maximum_based_methods_with_zero_membership_return_midpoint() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0];
+    let mus = vec![0.0, 0.0, 0.0, 0.0];
+    assert_eq!(bisector(&xs, &mus), 2.0);
+    assert_eq!(mean_of_maximum(&xs, &mus), 2.0);
+    assert_eq!(smallest_of_maximum(&xs, &mus), 2.0);
+    assert_eq!(largest_of_maximum(&xs, &mus), 2.0);
+}
+
+fn exact_centroid(
+    range: Range,
+    implied: &[(f64, MembershipKind)],
+    implication: Implication,
+    aggregation: Aggregation,
+) -> Option<f64> {
+    let knots = centroid_knots(range, implied.iter().map(|(_, kind)| kind));
+    piecewise_linear_centroid(
+        knots,
+        implied.iter().map(|(fire, kind)| (*fire, kind)),
+        implication,
+        aggregation,
+        &mut vec![[0.0; 3]; implied.len()],
+        &mut vec![0.0; implied.len()],
+    )
+}
+
+#[test]
+fn test_piecewise_linear_centroid_of_clipped_terms() {
+    let range = Range {
+        min: 0.0,
+        max: 30.0,
+    };
+    let ramp = MembershipKind::Triangle {
+        a: 0.0,
+        b: 0.0,
+        c: 30.0,
+    };
+    // a right triangle: one third of the way from the right angle
+    let c = exact_centroid(
+        range,
+        &[(1.0, ramp.clone())],
+        Implication::Min,
+        Aggregation::Max,
+    );
+    assert!((c.unwrap() - 10.0).abs() < 1e-12);
+
+    // clipped at 0.5: a 15 x 0.5 rectangle and a 15 x 0.5 triangle, moments 56.25 + 75
+    let c = exact_centroid(range, &[(0.5, ramp)], Implication::Min, Aggregation::Max);
+    assert!((c.unwrap() - 131.25 / 11.25).abs() < 1e-12);
+
+    // nothing fired
+    let peak = MembershipKind::Triangle {
+        a: 10.0,
+        b: 15.0,
+        c: 20.0,
+    };
+    let c = exact_centroid(range, &[(0.0, peak)], Implication::Min, Aggregation::Max);
+    assert_eq!(c, None);
+}
+
+#[test]
+fn test_piecewise_linear_centroid_matches_dense_sampling() {
+    let range = Range {
+        min: 0.0,
+        max: 100.0,
+    };
+    let implied = [
+        (
+            0.7,
+            MembershipKind::Trapezoid {
+                a: 0.0,
+                b: 0.0,
+                c: 20.0,
+                d: 45.0,
+            },
+        ),
+        (
+            0.4,
+            MembershipKind::Triangle {
+                a: 20.0,
+                b: 50.0,
+                c: 80.0,
+            },
+        ),
+        (
+            0.9,
+            MembershipKind::Trapezoid {
+                a: 60.0,
+                b: 90.0,
+                c: 100.0,
+                d: 120.0,
+            },
+        ),
+        // a second rule on the same term, below the first one
+        (
+            0.2,
+            MembershipKind::Triangle {
+                a: 20.0,
+                b: 50.0,
+                c: 80.0,
+            },
+        ),
+        // point lists, with a vertical edge at 40
+        (
+            0.6,
+            MembershipKind::Polygon {
+                points: vec![
+                    (30.0, 0.0),
+                    (35.0, 0.8),
+                    (40.0, 0.3),
+                    (40.0, 0.6),
+                    (55.0, 0.0),
+                ],
+            },
+        ),
+        (
+            0.5,
+            MembershipKind::Sampled {
+                min: 50.0,
+                max: 90.0,
+                values: vec![0.0, 0.5, 1.0, 0.2, 0.7],
+            },
+        ),
+    ];
+    let xs = linspace(range.min, range.max, 200_001);
+
+    for implication in [
+        Implication::Min,
+        Implication::Product,
+        Implication::Lukasiewicz,
+    ] {
+        for aggregation in [
+            Aggregation::Max,
+            Aggregation::BoundedSum,
+            Aggregation::NormalizedSum,
+        ] {
+            let mut agg: Vec<f64> = xs
+                .iter()
+                .map(|x| {
+                    implied.iter().fold(0.0, |acc, (fire, kind)| {
+                        aggregation.apply(acc, implication.apply(*fire, kind.degree(*x)))
+                    })
+                })
+                .collect();
+            aggregation.normalize(&mut agg);
+            let sampled = centroid(&xs, &agg);
+            let exact = exact_centroid(range, &implied, implication, aggregation).unwrap();
+            assert!(
+                (exact - sampled).abs() < 1e-3,
+                "{:?} {:?}: exact {} vs sampled {}",
+                implication,
+                aggregation,
+                exact,
+                sampled
+            );
+        }
+    }
+}
+
+#[test]
+fn test_exact_centroid_applies_to_piecewise_linear_sets_only() {
+    let triangle = MembershipKind::Triangle {
+        a: 0.0,
+        b: 1.0,
+        c: 2.0,
+    };
+    let gauss = MembershipKind::Gauss {
+        sigma: 1.0,
+        mu: 1.0,
+    };
+    assert!(is_piecewise_linear([&triangle], Aggregation::Max));
+    let polygon = MembershipKind::Polygon {
+        points: vec![(0.0, 0.2), (1.5, 1.0)],
+    };
+    assert!(is_piecewise_linear([&triangle, &polygon], Aggregation::Max));
+    assert!(!is_piecewise_linear([&triangle, &gauss], Aggregation::Max));
+    assert!(!is_piecewise_linear(
+        [&triangle],
+        Aggregation::ProbabilisticSum
+    ));
+
+    // sorted, without duplicates, clipped to the range
+    let trapezoid = MembershipKind::Trapezoid {
+        a: -1.0,
+        b: 0.5,
+        c: 1.0,
+        d: 3.0,
+    };
+    let knots = centroid_knots(Range { min: 0.0, max: 2.0 }, [&triangle, &trapezoid]);
+    assert_eq!(knots, vec![0.0, 0.5, 1.0, 2.0]);
+}
+
+#[test]
+fn test_singleton_centroid_aggregates_rules_on_the_same_point() {
+    let low: MembershipKind = MembershipKind::Singleton { value: 20.0 };
+    let high = MembershipKind::Constant { value: 80.0 };
+    let implied = [(0.6, &low), (0.3, &high), (0.2, &low)];
+
+    // max: heights 0.6 and 0.3
+    let c = singleton_centroid(implied.into_iter(), Implication::Min, Aggregation::Max);
+    assert!((c.unwrap() - (0.6 * 20.0 + 0.3 * 80.0) / 0.9).abs() < 1e-12);
+    // bounded sum: heights 0.8 and 0.3
+    let c = singleton_centroid(
+        implied.into_iter(),
+        Implication::Min,
+        Aggregation::BoundedSum,
+    );
+    assert!((c.unwrap() - (0.8 * 20.0 + 0.3 * 80.0) / 1.1).abs() < 1e-12);
+
+    let nothing = [(0.0, &low), (0.0, &high)];
+    assert_eq!(
+        singleton_centroid(nothing.into_iter(), Implication::Min, Aggregation::Max),
+        None
+    );
+
+    let triangle = MembershipKind::Triangle {
+        a: 0.0,
+        b: 1.0,
+        c: 2.0,
+    };
+    assert!(are_singletons([&low, &high]));
+    assert!(!are_singletons([&low, &triangle]));
+}
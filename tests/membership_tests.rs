@@ -1,8 +1,11 @@
-use fuzzy_logic_engine_rs::membership::MembershipKind;
+use fuzzy_logic_engine_rs::{
+    membership::{Membership, MembershipKind},
+    variable::Range,
+};
 
 #[test]
 fn test_triangle_basic_shape_type_of_membership_kind() {
-    let tri = MembershipKind::Triangle {
+    let tri: MembershipKind = MembershipKind::Triangle {
         a: 0.0,
         b: 5.0,
         c: 10.0,
@@ -41,7 +44,7 @@ fn test_triangle_edges_type_of_membership_kind() {
 
 #[test]
 fn test_triangle_symmetry_of_membership_kind() {
-    let tri = MembershipKind::Triangle {
+    let tri: MembershipKind = MembershipKind::Triangle {
         a: 0.0,
         b: 5.0,
         c: 10.0,
@@ -55,7 +58,7 @@ fn test_triangle_symmetry_of_membership_kind() {
 
 #[test]
 fn test_trapezoid_basic_shape_type_of_membership_kind() {
-    let trap = MembershipKind::Trapezoid {
+    let trap: MembershipKind = MembershipKind::Trapezoid {
         a: 0.0,
         b: 2.0,
         c: 4.0,
@@ -92,9 +95,150 @@ fn test_trapezoid_edges_type_of_membership_kind() {
     assert_eq!(trap.degree(6.0), 0.0);
 }
 
+#[test]
+fn test_vertical_edges_are_fully_true() {
+    let poor: MembershipKind = MembershipKind::Triangle {
+        a: 0.0,
+        b: 0.0,
+        c: 5.0,
+    };
+    assert_eq!(poor.degree(0.0), 1.0);
+    assert_eq!(poor.degree(2.5), 0.5);
+    assert_eq!(poor.degree(-1.0), 0.0);
+
+    let excellent: MembershipKind = MembershipKind::Triangle {
+        a: 5.0,
+        b: 10.0,
+        c: 10.0,
+    };
+    assert_eq!(excellent.degree(10.0), 1.0);
+
+    let trap: MembershipKind = MembershipKind::Trapezoid {
+        a: 0.0,
+        b: 0.0,
+        c: 10.0,
+        d: 25.0,
+    };
+    assert_eq!(trap.degree(0.0), 1.0);
+    assert_eq!(trap.degree(-0.1), 0.0);
+    let trap: MembershipKind = MembershipKind::Trapezoid {
+        a: 10.0,
+        b: 20.0,
+        c: 30.0,
+        d: 30.0,
+    };
+    assert_eq!(trap.degree(30.0), 1.0);
+    assert_eq!(trap.degree(30.1), 0.0);
+}
+
+#[test]
+fn test_shoulders_saturate_beyond_the_boundary() {
+    let left: MembershipKind = MembershipKind::LeftShoulder { a: 0.0, b: 4.0 };
+    let right: MembershipKind = MembershipKind::RightShoulder { a: 6.0, b: 10.0 };
+    for (x, expected) in [
+        (-100.0, 1.0),
+        (0.0, 1.0),
+        (1.0, 0.75),
+        (4.0, 0.0),
+        (50.0, 0.0),
+    ] {
+        assert_eq!(left.degree(x), expected, "left({})", x);
+        assert_eq!(right.degree(10.0 - x), expected, "right({})", 10.0 - x);
+    }
+    assert_eq!(left.peak(), Some(0.0));
+    assert_eq!(right.peak(), Some(10.0));
+    assert_eq!(
+        left.breakpoints().unwrap().collect::<Vec<_>>(),
+        vec![0.0, 4.0]
+    );
+
+    // monotonic, so usable as Tsukamoto consequents
+    assert_eq!(left.inverse(0.25), Some(3.0));
+    assert_eq!(right.inverse(0.25), Some(7.0));
+
+    // a == b is a crisp step
+    let step: MembershipKind = MembershipKind::RightShoulder { a: 5.0, b: 5.0 };
+    assert_eq!(step.degree(4.9), 0.0);
+    assert_eq!(step.degree(5.0), 1.0);
+    assert_eq!(step.inverse(0.5), None);
+}
+
+#[test]
+fn test_polygon_interpolates_between_points() {
+    let polygon: MembershipKind = MembershipKind::Polygon {
+        points: vec![(0.0, 0.2), (2.0, 1.0), (4.0, 1.0), (4.0, 0.5), (8.0, 0.0)],
+    };
+    for (x, expected) in [
+        (-5.0, 0.2),
+        (0.0, 0.2),
+        (1.0, 0.6),
+        (3.0, 1.0),
+        (4.0, 0.5),
+        (6.0, 0.25),
+        (20.0, 0.0),
+    ] {
+        assert!(
+            (polygon.degree(x) - expected).abs() < 1e-12,
+            "polygon({})",
+            x
+        );
+    }
+    assert_eq!(polygon.peak(), Some(3.0));
+    assert_eq!(
+        polygon.breakpoints().unwrap().collect::<Vec<_>>(),
+        vec![0.0, 2.0, 4.0, 4.0, 8.0]
+    );
+
+    let empty: MembershipKind = MembershipKind::Polygon { points: vec![] };
+    assert_eq!(empty.degree(1.0), 0.0);
+    assert_eq!(empty.peak(), None);
+}
+
+#[test]
+fn test_sampled_interpolates_between_samples() {
+    let sampled: MembershipKind = MembershipKind::Sampled {
+        min: 10.0,
+        max: 20.0,
+        values: vec![0.0, 0.4, 1.0, 1.0, 0.6, 0.2],
+    };
+    for (x, expected) in [
+        (0.0, 0.0),
+        (11.0, 0.2),
+        (14.0, 1.0),
+        (17.0, 0.8),
+        (20.0, 0.2),
+        (25.0, 0.2),
+    ] {
+        assert!(
+            (sampled.degree(x) - expected).abs() < 1e-12,
+            "sampled({})",
+            x
+        );
+    }
+    assert_eq!(sampled.peak(), Some(15.0));
+    assert_eq!(
+        sampled.breakpoints().unwrap().collect::<Vec<_>>(),
+        vec![10.0, 12.0, 14.0, 16.0, 18.0, 20.0]
+    );
+
+    // the same shape as a polygon
+    let polygon: MembershipKind = MembershipKind::Polygon {
+        points: vec![
+            (10.0, 0.0),
+            (12.0, 0.4),
+            (14.0, 1.0),
+            (16.0, 1.0),
+            (20.0, 0.2),
+        ],
+    };
+    for x in [9.0, 12.5, 15.0, 18.3, 21.0] {
+        assert!((sampled.degree(x) - polygon.degree(x)).abs() < 1e-12);
+    }
+}
+
 #[test]
 fn test_gauss_peak_and_symmetry_type_of_membership_kind() {
-    let gauss = MembershipKind::Gauss {
+    let gauss: MembershipKind = MembershipKind::Gauss {
         sigma: 1.0,
         mu: 0.0,
     };
@@ -123,3 +267,282 @@ fn test_gauss_falloff_type_of_membership_kind() {
     // Very far -> close to 0
     assert!(gauss.degree(10.0) < 1e-10);
 }
+
+#[test]
+fn test_sugeno_constant_and_linear_evaluate() {
+    let constant = MembershipKind::Constant { value: 3.5 };
+    assert_eq!(constant.evaluate(&[1.0, 2.0]), Some(3.5));
+    assert_eq!(constant.degree(3.5), 1.0);
+    assert_eq!(constant.degree(3.0), 0.0);
+
+    let linear: MembershipKind = MembershipKind::Linear {
+        coefficients: vec![1.0, -2.0],
+        constant: 0.5,
+    };
+    // 0.5 + 1*3 - 2*1 = 1.5
+    assert!((linear.evaluate(&[3.0, 1.0]).unwrap() - 1.5).abs() < 1e-9);
+    // Arity mismatch
+    assert_eq!(linear.evaluate(&[3.0]), None);
+
+    // Shaped kinds are not Sugeno consequents
+    let tri = MembershipKind::Triangle {
+        a: 0.0,
+        b: 1.0,
+        c: 2.0,
+    };
+    assert_eq!(tri.evaluate(&[1.0]), None);
+}
+
+#[test]
+fn test_singleton_is_a_crisp_point() {
+    let singleton: MembershipKind = MembershipKind::Singleton { value: 40.0 };
+    assert_eq!(singleton.degree(40.0), 1.0);
+    assert_eq!(singleton.degree(40.5), 0.0);
+    assert_eq!(singleton.peak(), Some(40.0));
+    // also a zero-order Sugeno consequent
+    assert_eq!(singleton.evaluate(&[1.0, 2.0]), Some(40.0));
+    assert!(singleton.breakpoints().is_none());
+}
+
+#[test]
+fn test_inverse_of_monotonic_ramps() {
+    let rising: MembershipKind = MembershipKind::Triangle {
+        a: 0.0,
+        b: 10.0,
+        c: 10.0,
+    };
+    assert!((rising.inverse(0.3).unwrap() - 3.0).abs() < 1e-9);
+    assert!((rising.degree(rising.inverse(0.3).unwrap()) - 0.3).abs() < 1e-9);
+
+    let falling: MembershipKind = MembershipKind::Trapezoid {
+        a: 0.0,
+        b: 0.0,
+        c: 2.0,
+        d: 6.0,
+    };
+    assert!((falling.inverse(0.5).unwrap() - 4.0).abs() < 1e-9);
+    assert!((falling.degree(falling.inverse(0.5).unwrap()) - 0.5).abs() < 1e-9);
+
+    // Smooth monotonic shapes are inverted in closed form
+    let smooth: [MembershipKind; 4] = [
+        MembershipKind::Sigmoid { a: 2.0, c: 5.0 },
+        MembershipKind::Sigmoid { a: -0.5, c: 1.0 },
+        MembershipKind::SShape { a: 2.0, b: 8.0 },
+        MembershipKind::ZShape { a: 2.0, b: 8.0 },
+    ];
+    for kind in &smooth {
+        for mu in [0.01, 0.25, 0.5, 0.6, 0.99] {
+            let x = kind.inverse(mu).unwrap();
+            assert!((kind.degree(x) - mu).abs() < 1e-9, "{:?} at {}", kind, mu);
+        }
+    }
+    assert_eq!(smooth[2].inverse(0.0), Some(2.0));
+    assert_eq!(smooth[2].inverse(1.0), Some(8.0));
+    assert_eq!(smooth[3].inverse(1.0), Some(2.0));
+    // a sigmoid never reaches 0 or 1, the ends stay finite
+    let ends = [
+        smooth[0].inverse(0.0).unwrap(),
+        smooth[0].inverse(1.0).unwrap(),
+    ];
+    assert!(ends[0] < 0.0 && ends[0] > -20.0);
+    assert!(ends[1] > 10.0 && ends[1] < 30.0);
+
+    // Peaked shapes have no single inverse
+    let peak = MembershipKind::Triangle {
+        a: 0.0,
+        b: 5.0,
+        c: 10.0,
+    };
+    assert_eq!(peak.inverse(0.5), None);
+    assert_eq!(
+        MembershipKind::Gauss {
+            sigma: 1.0,
+            mu: 0.0
+        }
+        .inverse(0.5),
+        None
+    );
+}
+
+#[test]
+fn test_bell_and_sigmoid_shapes() {
+    let bell: MembershipKind = MembershipKind::Bell {
+        a: 2.0,
+        b: 4.0,
+        c: 6.0,
+    };
+    assert_eq!(bell.degree(6.0), 1.0);
+    // half height at c ± a, symmetric
+    assert!((bell.degree(4.0) - 0.5).abs() < 1e-12);
+    assert!((bell.degree(8.0) - 0.5).abs() < 1e-12);
+    // gbellmf(3, [2 4 6]) = 1 / (1 + 1.5^8)
+    assert!((bell.degree(3.0) - 1.0 / (1.0 + 1.5f64.powi(8))).abs() < 1e-12);
+
+    let rising: MembershipKind = MembershipKind::Sigmoid { a: 2.0, c: 4.0 };
+    assert!((rising.degree(4.0) - 0.5).abs() < 1e-12);
+    assert!((rising.degree(5.0) - 1.0 / (1.0 + (-2.0f64).exp())).abs() < 1e-12);
+    assert!(rising.degree(0.0) < 1e-3 && rising.degree(8.0) > 1.0 - 1e-3);
+    let falling: MembershipKind = MembershipKind::Sigmoid { a: -2.0, c: 4.0 };
+    assert!((falling.degree(3.0) - rising.degree(5.0)).abs() < 1e-12);
+
+    // plateaus between the two crossover points
+    let diff: MembershipKind = MembershipKind::DiffSigmoid {
+        a1: 5.0,
+        c1: 2.0,
+        a2: 5.0,
+        c2: 7.0,
+    };
+    let prod: MembershipKind = MembershipKind::ProdSigmoid {
+        a1: 5.0,
+        c1: 2.0,
+        a2: -5.0,
+        c2: 7.0,
+    };
+    for kind in [&diff, &prod] {
+        assert!(kind.degree(4.5) > 0.99);
+        assert!(kind.degree(0.0) < 1e-3 && kind.degree(9.0) < 1e-3);
+        assert!((kind.degree(2.0) - 0.5).abs() < 1e-3);
+    }
+    assert_eq!(diff.peak(), Some(4.5));
+}
+
+#[test]
+fn test_s_z_and_pi_shapes() {
+    let s: MembershipKind = MembershipKind::SShape { a: 1.0, b: 8.0 };
+    let z: MembershipKind = MembershipKind::ZShape { a: 1.0, b: 8.0 };
+    for (x, expected) in [
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (2.75, 0.125),
+        (4.5, 0.5),
+        (8.0, 1.0),
+        (9.0, 1.0),
+    ] {
+        assert!((s.degree(x) - expected).abs() < 1e-12, "smf({})", x);
+        assert!((z.degree(x) - (1.0 - expected)).abs() < 1e-12, "zmf({})", x);
+    }
+    // smooth: the upper half mirrors the lower half
+    assert!((s.degree(6.25) - 0.875).abs() < 1e-12);
+
+    let pi: MembershipKind = MembershipKind::PiShape {
+        a: 1.0,
+        b: 4.0,
+        c: 5.0,
+        d: 10.0,
+    };
+    assert_eq!(pi.degree(1.0), 0.0);
+    assert_eq!(pi.degree(4.5), 1.0);
+    assert!((pi.degree(2.5) - 0.5).abs() < 1e-12);
+    assert!((pi.degree(7.5) - 0.5).abs() < 1e-12);
+    assert_eq!(pi.degree(10.0), 0.0);
+    assert_eq!(pi.peak(), Some(4.5));
+}
+
+#[test]
+fn test_two_sided_gauss() {
+    let gauss2: MembershipKind = MembershipKind::Gauss2 {
+        sigma1: 1.0,
+        mu1: 3.0,
+        sigma2: 2.0,
+        mu2: 5.0,
+    };
+    assert_eq!(gauss2.degree(3.0), 1.0);
+    assert_eq!(gauss2.degree(4.0), 1.0);
+    assert_eq!(gauss2.degree(5.0), 1.0);
+    // each side follows its own sigma
+    assert!((gauss2.degree(2.0) - (-0.5f64).exp()).abs() < 1e-12);
+    assert!((gauss2.degree(7.0) - (-0.5f64).exp()).abs() < 1e-12);
+
+    // overlapping centers: the two halves multiply, the top stays below 1
+    let narrow: MembershipKind = MembershipKind::Gauss2 {
+        sigma1: 1.0,
+        mu1: 5.0,
+        sigma2: 1.0,
+        mu2: 3.0,
+    };
+    assert!((narrow.degree(4.0) - (-1.0f64).exp()).abs() < 1e-12);
+    assert!(narrow.breakpoints().is_none());
+}
+
+// Hill equation: the share of the maximal response at a dose
+#[derive(Debug)]
+struct DoseResponse {
+    ec50: f64,
+    hill: f64,
+}
+
+impl Membership for DoseResponse {
+    fn degree(&self, dose: f64) -> f64 {
+        1.0 / (1.0 + (self.ec50 / dose).powf(self.hill))
+    }
+
+    fn support(&self) -> Range {
+        Range {
+            min: 0.0,
+            max: f64::INFINITY,
+        }
+    }
+
+    fn core(&self) -> Range {
+        Range {
+            min: 10.0 * self.ec50,
+            max: f64::INFINITY,
+        }
+    }
+
+    fn derivative(&self, dose: f64) -> Option<f64> {
+        let (e, x, n) = (self.ec50.powf(self.hill), dose.powf(self.hill), self.hill);
+        Some(n * e * x / dose / (e + x).powi(2))
+    }
+}
+
+#[derive(Debug)]
+struct Plateau;
+
+impl Membership for Plateau {
+    fn degree(&self, x: f64) -> f64 {
+        if (2.0..=4.0).contains(&x) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn support(&self) -> Range {
+        Range { min: 2.0, max: 4.0 }
+    }
+
+    fn core(&self) -> Range {
+        Range { min: 2.0, max: 4.0 }
+    }
+}
+
+#[test]
+fn test_custom_membership_through_trait() {
+    let effective: MembershipKind = MembershipKind::custom(DoseResponse {
+        ec50: 2.0,
+        hill: 3.0,
+    });
+    assert_eq!(effective.degree(0.0), 0.0);
+    assert_eq!(effective.degree(2.0), 0.5);
+    assert!(effective.degree(100.0) > 0.99);
+    // the finite end of the open core
+    assert_eq!(effective.peak(), Some(20.0));
+    assert_eq!(effective.inverse(0.5), None);
+    assert!(effective.breakpoints().is_none());
+
+    let MembershipKind::Custom(curve) = effective.clone() else {
+        panic!("expected a custom kind");
+    };
+    let slope = (curve.degree(2.001) - curve.degree(1.999)) / 0.002;
+    assert!((curve.derivative(2.0).unwrap() - slope).abs() < 1e-6);
+    assert!(format!("{:?}", effective).contains("DoseResponse"));
+
+    let plateau: MembershipKind = MembershipKind::custom(Plateau);
+    assert_eq!(plateau.degree(3.0), 1.0);
+    assert_eq!(plateau.peak(), Some(3.0));
+    let MembershipKind::Custom(plateau) = plateau else {
+        panic!("expected a custom kind");
+    };
+    assert_eq!(plateau.derivative(3.0), None);
+}
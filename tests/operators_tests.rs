@@ -0,0 +1,344 @@
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    membership::MembershipKind as M,
+    operators::{Aggregation, Complement, Implication, SNorm, TNorm},
+    rule::{Connective, Rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+const T_NORMS: [TNorm; 6] = [
+    TNorm::Min,
+    TNorm::Product,
+    TNorm::Lukasiewicz,
+    TNorm::Drastic,
+    TNorm::Einstein,
+    TNorm::Hamacher(0.5),
+];
+
+const S_NORMS: [SNorm; 6] = [
+    SNorm::Max,
+    SNorm::ProbabilisticSum,
+    SNorm::BoundedSum,
+    SNorm::Drastic,
+    SNorm::Einstein,
+    SNorm::Hamacher(0.5),
+];
+
+#[test]
+fn test_t_norm_values() {
+    assert_eq!(TNorm::Min.apply(0.4, 0.7), 0.4);
+    assert!((TNorm::Product.apply(0.4f64, 0.5) - 0.2).abs() < 1e-9);
+    assert!((TNorm::Lukasiewicz.apply(0.4f64, 0.7) - 0.1).abs() < 1e-9);
+    assert_eq!(TNorm::Lukasiewicz.apply(0.2, 0.3), 0.0);
+    assert_eq!(TNorm::Drastic.apply(0.4, 0.7), 0.0);
+    assert_eq!(TNorm::Drastic.apply(1.0, 0.7), 0.7);
+    // 0.25 / (2 - 0.75) = 0.2
+    assert!((TNorm::Einstein.apply(0.5f64, 0.5) - 0.2).abs() < 1e-9);
+    // Hamacher with p = 1 is the algebraic product
+    assert!((TNorm::Hamacher(1.0).apply(0.4f64, 0.5) - 0.2).abs() < 1e-9);
+    assert_eq!(TNorm::Hamacher(0.0).apply(0.0, 0.0), 0.0);
+}
+
+#[test]
+fn test_s_norm_values() {
+    assert_eq!(SNorm::Max.apply(0.4, 0.7), 0.7);
+    assert!((SNorm::ProbabilisticSum.apply(0.4f64, 0.5) - 0.7).abs() < 1e-9);
+    assert_eq!(SNorm::BoundedSum.apply(0.4, 0.7), 1.0);
+    assert_eq!(SNorm::Drastic.apply(0.4, 0.7), 1.0);
+    assert_eq!(SNorm::Drastic.apply(0.0, 0.7), 0.7);
+    // 1.0 / 1.25 = 0.8
+    assert!((SNorm::Einstein.apply(0.5f64, 0.5) - 0.8).abs() < 1e-9);
+    // Hamacher with p = 1 is the probabilistic sum
+    assert!((SNorm::Hamacher(1.0).apply(0.4f64, 0.5) - 0.7).abs() < 1e-9);
+}
+
+#[test]
+fn test_norms_boundary_conditions() {
+    for x in [0.0f64, 0.3, 0.8, 1.0] {
+        for t in T_NORMS {
+            assert!((t.apply(x, 1.0) - x).abs() < 1e-9, "{:?}", t);
+            assert!(t.apply(x, 0.0).abs() < 1e-9, "{:?}", t);
+        }
+        for s in S_NORMS {
+            assert!((s.apply(x, 0.0) - x).abs() < 1e-9, "{:?}", s);
+            assert!((s.apply(x, 1.0) - 1.0).abs() < 1e-9, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_norms_are_commutative_and_bounded_by_min_max() {
+    let samples = [0.0f64, 0.1, 0.35, 0.5, 0.9, 1.0];
+    for a in samples {
+        for b in samples {
+            for t in T_NORMS {
+                assert!((t.apply(a, b) - t.apply(b, a)).abs() < 1e-9);
+                assert!(t.apply(a, b) <= a.min(b) + 1e-9);
+            }
+            for s in S_NORMS {
+                assert!((s.apply(a, b) - s.apply(b, a)).abs() < 1e-9);
+                assert!(s.apply(a, b) >= a.max(b) - 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fis_uses_configured_and_or_methods() {
+    let mut a = LinguisticVariable::new(
+        "a",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    a.add_term(Term::new(
+        "high",
+        M::Triangle {
+            a: 0.0,
+            b: 10.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+    let mut b = a.clone();
+    b.name = "b".into();
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    y.add_term(Term::new("on", M::Constant { value: 10.0 }))
+        .unwrap();
+    y.add_term(Term::new("off", M::Constant { value: 0.0 }))
+        .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Operators");
+    fis.add_input(a);
+    fis.add_input(b);
+    fis.add_output(y);
+    fis.set_rules(vec![
+        Rule::new(
+            vec![Some("high".into()), Some("high".into())],
+            vec!["on".into()],
+            Connective::And,
+        ),
+        Rule::new(vec![None, None], vec!["off".into()], Connective::And),
+    ]);
+
+    // a = 5 (0.5), b = 8 (0.8): min gives 0.5, product gives 0.4
+    let with_min = fis.compute(FisType::Sugeno, &[5.0, 8.0]).unwrap()[0];
+    assert!((with_min - 10.0 * 0.5 / 1.5).abs() < 1e-9);
+
+    fis.set_and_method(TNorm::Product);
+    let with_product = fis.compute(FisType::Sugeno, &[5.0, 8.0]).unwrap()[0];
+    assert!((with_product - 10.0 * 0.4 / 1.4).abs() < 1e-9);
+
+    // OR connective: max gives 0.8, probabilistic sum gives 0.9
+    fis.rules[0].connective = Connective::Or;
+    let with_max = fis.compute(FisType::Sugeno, &[5.0, 8.0]).unwrap()[0];
+    assert!((with_max - 10.0 * 0.8 / 1.8).abs() < 1e-9);
+
+    fis.set_or_method(SNorm::ProbabilisticSum);
+    let with_probor = fis.compute(FisType::Sugeno, &[5.0, 8.0]).unwrap()[0];
+    assert!((with_probor - 10.0 * 0.9 / 1.9).abs() < 1e-9);
+}
+
+#[test]
+fn test_implication_values() {
+    assert_eq!(Implication::Min.apply(0.5, 0.8), 0.5);
+    assert!((Implication::Product.apply(0.5f64, 0.8) - 0.4).abs() < 1e-9);
+    assert!((Implication::Lukasiewicz.apply(0.5f64, 0.8) - 0.3).abs() < 1e-9);
+    assert_eq!(Implication::Lukasiewicz.apply(0.2, 0.3), 0.0);
+}
+
+#[test]
+fn test_aggregation_values() {
+    assert_eq!(Aggregation::Max.apply(0.5, 0.8), 0.8);
+    assert_eq!(Aggregation::BoundedSum.apply(0.5, 0.8), 1.0);
+    assert!((Aggregation::ProbabilisticSum.apply(0.5f64, 0.8) - 0.9).abs() < 1e-9);
+    assert!((Aggregation::NormalizedSum.apply(0.5f64, 0.8) - 1.3).abs() < 1e-9);
+
+    let mut agg = vec![0.0, 1.0, 2.0];
+    Aggregation::NormalizedSum.normalize(&mut agg);
+    assert_eq!(agg, vec![0.0, 0.5, 1.0]);
+
+    // Other methods and already normalized sums are left untouched
+    let mut agg = vec![0.0, 0.5, 0.8];
+    Aggregation::NormalizedSum.normalize(&mut agg);
+    Aggregation::Max.normalize(&mut agg);
+    assert_eq!(agg, vec![0.0, 0.5, 0.8]);
+}
+
+fn single_output_system() -> FuzzyInferenceSystem {
+    let mut x = LinguisticVariable::new(
+        "x",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    x.add_term(Term::new(
+        "high",
+        M::Triangle {
+            a: 0.0,
+            b: 10.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+
+    let mut y = LinguisticVariable::new(
+        "y",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    y.add_term(Term::new(
+        "skewed",
+        M::Triangle {
+            a: 0.0,
+            b: 2.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("Implication");
+    fis.resolution = 1001;
+    fis.add_input(x);
+    fis.add_output(y);
+    fis.set_rules(vec![Rule::new(
+        vec![Some("high".into())],
+        vec!["skewed".into()],
+        Connective::And,
+    )]);
+    fis
+}
+
+#[test]
+fn test_product_implication_scales_instead_of_clipping() {
+    let mut fis = single_output_system();
+    fis.set_defuzzifier(Defuzzifier::MeanOfMaximum);
+
+    // fire = 0.5: clipping leaves a plateau over [1, 6], scaling keeps the peak at 2
+    let clipped = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((clipped - 3.5).abs() < 0.02);
+
+    fis.set_implication(Implication::Product);
+    let scaled = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((scaled - 2.0).abs() < 0.02);
+}
+
+#[test]
+fn test_aggregation_combines_overlapping_rules() {
+    let mut fis = single_output_system();
+    let rule = fis.rules[0].clone();
+    fis.rules.push(rule);
+    // Report the height of the aggregated set
+    fis.set_defuzzifier(Defuzzifier::custom(|_xs, mus| {
+        mus.iter().copied().fold(0.0, f64::max)
+    }));
+
+    // Two identical rules fired at 0.5
+    let max = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((max - 0.5).abs() < 1e-9);
+
+    fis.set_aggregation(Aggregation::BoundedSum);
+    let bounded = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((bounded - 1.0).abs() < 1e-9);
+
+    fis.set_aggregation(Aggregation::ProbabilisticSum);
+    let probor = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((probor - 0.75).abs() < 1e-9);
+
+    fis.set_aggregation(Aggregation::NormalizedSum);
+    let normalized = fis.compute(FisType::Mamdani, &[5.0]).unwrap()[0];
+    assert!((normalized - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_complement_values() {
+    assert!((Complement::Standard.apply(0.3f64) - 0.7).abs() < 1e-9);
+    // lambda = 0 and w = 1 reduce to the standard complement
+    assert!((Complement::Sugeno(0.0).apply(0.3f64) - 0.7).abs() < 1e-9);
+    assert!((Complement::Yager(1.0).apply(0.3f64) - 0.7).abs() < 1e-9);
+    // (1 - 0.5) / (1 + 1 * 0.5) = 1/3
+    assert!((Complement::Sugeno(1.0).apply(0.5f64) - 1.0 / 3.0).abs() < 1e-9);
+    // (1 - 0.25)^(1/2)
+    assert!((Complement::Yager(2.0).apply(0.5) - 0.75f64.sqrt()).abs() < 1e-9);
+
+    for c in [
+        Complement::Standard,
+        Complement::Sugeno(2.0),
+        Complement::Yager(3.0),
+    ] {
+        assert!((c.apply(0.0f64) - 1.0).abs() < 1e-9);
+        assert!(c.apply(1.0f64).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_negated_antecedent_uses_configured_complement() {
+    let mut temperature = LinguisticVariable::new(
+        "temperature",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    temperature
+        .add_term(Term::new(
+            "hot",
+            M::Triangle {
+                a: 0.0,
+                b: 10.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+
+    let mut heater = LinguisticVariable::new(
+        "heater",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    heater
+        .add_term(Term::new("on", M::Constant { value: 10.0 }))
+        .unwrap();
+    heater
+        .add_term(Term::new("off", M::Constant { value: 0.0 }))
+        .unwrap();
+
+    let mut fis: FuzzyInferenceSystem = FuzzyInferenceSystem::new("Negation");
+    fis.add_input(temperature);
+    fis.add_output(heater);
+    // IF temperature IS NOT hot THEN heater IS on
+    // IF temperature IS hot THEN heater IS off
+    fis.set_rules(vec![
+        Rule::new(vec![Some("hot".into())], vec!["on".into()], Connective::And).negate(0),
+        Rule::new(
+            vec![Some("hot".into())],
+            vec!["off".into()],
+            Connective::And,
+        ),
+    ]);
+
+    // temperature = 2.5 -> hot 0.25, NOT hot 0.75 -> 7.5
+    let standard = fis.compute(FisType::Sugeno, &[2.5]).unwrap()[0];
+    assert!((standard - 7.5).abs() < 1e-9);
+
+    // Yager w = 2: NOT hot = sqrt(1 - 0.0625)
+    fis.set_complement(Complement::Yager(2.0));
+    let not_hot = (1.0f64 - 0.0625).sqrt();
+    let yager = fis.compute(FisType::Sugeno, &[2.5]).unwrap()[0];
+    assert!((yager - 10.0 * not_hot / (not_hot + 0.25)).abs() < 1e-9);
+}
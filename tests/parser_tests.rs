@@ -0,0 +1,317 @@
+use fuzzy_logic_engine_rs::{
+    fis::{FisError, FisType, FuzzyInferenceSystem},
+    membership::MembershipKind as M,
+    parser::RuleParseErrorKind,
+    rule::{Connective, Expression, Rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+fn tipping_system() -> FuzzyInferenceSystem {
+    let mut service = LinguisticVariable::new(
+        "service",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    service
+        .add_term(Term::new(
+            "poor",
+            M::Triangle {
+                a: 0.0,
+                b: 0.0,
+                c: 5.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "good",
+            M::Triangle {
+                a: 0.0,
+                b: 5.0,
+                c: 10.0,
+            },
+        ))
+        .unwrap();
+
+    let mut food = LinguisticVariable::new(
+        "food",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    food.add_term(Term::new(
+        "rancid",
+        M::Triangle {
+            a: 0.0,
+            b: 0.0,
+            c: 5.0,
+        },
+    ))
+    .unwrap();
+    food.add_term(Term::new(
+        "delicious",
+        M::Triangle {
+            a: 5.0,
+            b: 10.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+
+    let mut tip = LinguisticVariable::new(
+        "tip",
+        Range {
+            min: 0.0,
+            max: 30.0,
+        },
+    );
+    tip.add_term(Term::new(
+        "cheap",
+        M::Triangle {
+            a: 0.0,
+            b: 5.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+    tip.add_term(Term::new(
+        "generous",
+        M::Triangle {
+            a: 20.0,
+            b: 25.0,
+            c: 30.0,
+        },
+    ))
+    .unwrap();
+
+    let mut mood = LinguisticVariable::new("mood", Range { min: 0.0, max: 1.0 });
+    mood.add_term(Term::new(
+        "happy",
+        M::Triangle {
+            a: 0.0,
+            b: 1.0,
+            c: 1.0,
+        },
+    ))
+    .unwrap();
+
+    let mut fis = FuzzyInferenceSystem::new("Tipping");
+    fis.add_input(service);
+    fis.add_input(food);
+    fis.add_output(tip);
+    fis.add_output(mood);
+    fis
+}
+
+#[test]
+fn test_parse_flat_or_rule_with_weight() {
+    let fis = tipping_system();
+    let rule = fis
+        .parse_rule("IF service IS poor OR food IS rancid THEN tip IS cheap WITH 0.8")
+        .unwrap();
+
+    assert_eq!(
+        rule.antecedent,
+        vec![Some("poor".to_string()), Some("rancid".to_string())]
+    );
+    assert!(matches!(rule.connective, Connective::Or));
+    assert_eq!(rule.consequent, vec!["cheap".to_string()]);
+    assert_eq!(rule.weight, 0.8);
+    assert!(rule.expression.is_none());
+}
+
+#[test]
+fn test_parse_and_rule_with_wildcard_and_negation() {
+    let fis = tipping_system();
+    let rule = fis
+        .parse_rule("if food is not rancid then tip is generous, mood is happy")
+        .unwrap();
+
+    assert_eq!(rule.antecedent, vec![None, Some("rancid".to_string())]);
+    assert!(matches!(rule.connective, Connective::And));
+    assert!(!rule.is_negated(0));
+    assert!(rule.is_negated(1));
+    assert_eq!(
+        rule.consequent,
+        vec!["generous".to_string(), "happy".to_string()]
+    );
+    assert_eq!(rule.weight, 1.0);
+}
+
+#[test]
+fn test_parse_or_rule_with_skipped_variable_stays_positional() {
+    let with_ambience = || {
+        let mut fis = tipping_system();
+        let mut ambience = LinguisticVariable::new(
+            "ambience",
+            Range {
+                min: 0.0,
+                max: 10.0,
+            },
+        );
+        ambience
+            .add_term(Term::new(
+                "cozy",
+                M::Triangle {
+                    a: 5.0,
+                    b: 10.0,
+                    c: 10.0,
+                },
+            ))
+            .unwrap();
+        fis.add_input(ambience);
+        fis
+    };
+    let mut parsed = with_ambience();
+    let mut tree = with_ambience();
+
+    parsed
+        .add_rule_str("IF service IS poor OR ambience IS cozy THEN tip IS cheap")
+        .unwrap();
+    let rule = &parsed.rules[0];
+    assert_eq!(
+        rule.antecedent,
+        vec![Some("poor".to_string()), None, Some("cozy".to_string())]
+    );
+    assert!(matches!(rule.connective, Connective::Or));
+    assert!(rule.expression.is_none());
+
+    // The skipped food clause is neutral, as in the expression form
+    tree.set_rules(vec![Rule::from_expression(
+        Expression::or(vec![
+            Expression::is("service", "poor"),
+            Expression::is("ambience", "cozy"),
+        ]),
+        vec!["cheap".into(), "".into()],
+    )]);
+    for inputs in [[10.0, 0.0, 0.0], [4.0, 2.0, 6.0], [8.0, 9.0, 10.0]] {
+        assert_eq!(
+            parsed.compute(FisType::Mamdani, &inputs).unwrap(),
+            tree.compute(FisType::Mamdani, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_parse_nested_rule_keeps_expression() {
+    let fis = tipping_system();
+    let rule = fis
+        .parse_rule(
+            "IF (service IS poor AND food IS rancid) OR NOT (service IS good) THEN mood IS happy",
+        )
+        .unwrap();
+
+    assert_eq!(
+        rule.expression,
+        Some(Expression::or(vec![
+            Expression::and(vec![
+                Expression::is("service", "poor"),
+                Expression::is("food", "rancid"),
+            ]),
+            !Expression::is("service", "good"),
+        ]))
+    );
+    // Only the second output is addressed
+    assert_eq!(rule.consequent, vec!["".to_string(), "happy".to_string()]);
+}
+
+#[test]
+fn test_parsed_rule_matches_hand_written_rule() {
+    let mut parsed = tipping_system();
+    parsed
+        .add_rule_str("IF service IS poor AND food IS rancid THEN tip IS cheap")
+        .unwrap();
+    parsed
+        .add_rule_str("IF service IS good THEN tip IS generous")
+        .unwrap();
+
+    let mut manual = tipping_system();
+    manual.set_rules(vec![
+        Rule::new(
+            vec![Some("poor".into()), Some("rancid".into())],
+            vec!["cheap".into()],
+            Connective::And,
+        ),
+        Rule::new(
+            vec![Some("good".into()), None],
+            vec!["generous".into()],
+            Connective::And,
+        ),
+    ]);
+
+    let a = parsed.compute(FisType::Mamdani, &[3.0, 2.0]).unwrap();
+    let b = manual.compute(FisType::Mamdani, &[3.0, 2.0]).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_parse_error_spans_unknown_variable_and_term() {
+    let fis = tipping_system();
+
+    let text = "IF servce IS poor THEN tip IS cheap";
+    let err = fis.parse_rule(text).unwrap_err();
+    assert_eq!(err.kind, RuleParseErrorKind::UnknownInput("servce".into()));
+    assert_eq!(&text[err.span.clone()], "servce");
+
+    let text = "IF service IS poor THEN tip IS lavish";
+    let err = fis.parse_rule(text).unwrap_err();
+    assert_eq!(
+        err.kind,
+        RuleParseErrorKind::UnknownTerm {
+            variable: "tip".into(),
+            term: "lavish".into()
+        }
+    );
+    assert_eq!(&text[err.span.clone()], "lavish");
+
+    let text = "IF service IS poor THEN service IS poor";
+    let err = fis.parse_rule(text).unwrap_err();
+    assert_eq!(
+        err.kind,
+        RuleParseErrorKind::UnknownOutput("service".into())
+    );
+    assert_eq!(err.span, 24..31);
+}
+
+#[test]
+fn test_parse_error_on_syntax_and_weight() {
+    let fis = tipping_system();
+
+    let err = fis
+        .parse_rule("IF service poor THEN tip IS cheap")
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuleParseErrorKind::UnexpectedToken { ref found, .. } if found == "poor"
+    ));
+
+    let text = "IF service IS poor THEN";
+    let err = fis.parse_rule(text).unwrap_err();
+    assert!(matches!(err.kind, RuleParseErrorKind::UnexpectedEnd { .. }));
+    assert_eq!(err.span, text.len()..text.len());
+
+    let text = "IF service IS poor THEN tip IS cheap WITH 1.5";
+    let err = fis.parse_rule(text).unwrap_err();
+    assert_eq!(err.kind, RuleParseErrorKind::InvalidWeight("1.5".into()));
+    assert_eq!(&text[err.span.clone()], "1.5");
+
+    let err = fis
+        .parse_rule("IF service IS poor THEN tip IS cheap AND tip IS generous")
+        .unwrap_err();
+    assert_eq!(err.kind, RuleParseErrorKind::DuplicateOutput("tip".into()));
+}
+
+#[test]
+fn test_add_rule_str_reports_fis_error() {
+    let mut fis = tipping_system();
+    let err = fis.add_rule_str("IF x IS y THEN tip IS cheap").unwrap_err();
+    match err {
+        FisError::RuleParse(e) => assert_eq!(e.span, 3..4),
+        other => panic!("Expected FisError::RuleParse, got {:?}", other),
+    }
+    assert!(fis.rules.is_empty());
+}
@@ -1,4 +1,7 @@
-use fuzzy_logic_engine_rs::rule::{Connective, Rule};
+use fuzzy_logic_engine_rs::{
+    fis::FisError,
+    rule::{Connective, Expression, Rule},
+};
 
 #[test]
 fn test_create_rule() {
@@ -11,3 +14,81 @@ fn test_create_rule() {
     assert_eq!(rule.antecedent.len(), 2);
     assert_eq!(rule.consequent[0], "increase");
 }
+
+#[test]
+fn test_rule_weight_defaults_to_one() {
+    let rule = Rule::new(
+        vec![Some("cold".into())],
+        vec!["increase".into()],
+        Connective::And,
+    );
+    assert_eq!(rule.weight, 1.0);
+}
+
+#[test]
+fn test_rule_with_weight_accepts_unit_interval() {
+    let rule = Rule::new(
+        vec![Some("cold".into())],
+        vec!["increase".into()],
+        Connective::And,
+    )
+    .with_weight(0.25)
+    .unwrap();
+    assert_eq!(rule.weight, 0.25);
+}
+
+#[test]
+fn test_rule_with_weight_rejects_out_of_range() {
+    for weight in [-0.1, 1.5, f64::NAN] {
+        let result = Rule::new(
+            vec![Some("cold".into())],
+            vec!["increase".into()],
+            Connective::And,
+        )
+        .with_weight(weight);
+        assert!(matches!(result, Err(FisError::InvalidRuleWeight(_))));
+    }
+}
+
+#[test]
+fn test_rule_negate_marks_single_clause() {
+    let rule = Rule::new(
+        vec![Some("cold".into()), Some("high".into()), None],
+        vec!["increase".into()],
+        Connective::And,
+    )
+    .negate(1);
+
+    assert!(!rule.is_negated(0));
+    assert!(rule.is_negated(1));
+    assert!(!rule.is_negated(2));
+    assert!(!rule.is_negated(10));
+}
+
+#[test]
+fn test_expression_builders() {
+    let expr = Expression::or(vec![
+        Expression::and(vec![Expression::is("a", "x"), Expression::is("b", "y")]),
+        !Expression::is("c", "z"),
+    ]);
+
+    match &expr {
+        Expression::Or(operands) => {
+            assert_eq!(operands.len(), 2);
+            assert!(matches!(&operands[0], Expression::And(inner) if inner.len() == 2));
+            assert_eq!(
+                operands[1],
+                Expression::Not(Box::new(Expression::Is {
+                    variable: "c".into(),
+                    term: "z".into(),
+                }))
+            );
+        }
+        other => panic!("Expected Expression::Or, got {:?}", other),
+    }
+
+    let rule = Rule::from_expression(expr.clone(), vec!["high".into()]);
+    assert!(rule.antecedent.is_empty());
+    assert_eq!(rule.expression, Some(expr));
+    assert_eq!(rule.weight, 1.0);
+}
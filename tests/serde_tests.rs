@@ -0,0 +1,264 @@
+#![cfg(feature = "serde")]
+
+use fuzzy_logic_engine_rs::{
+    defuzzifier::Defuzzifier,
+    fis::{FisType, FuzzyInferenceSystem},
+    membership::{Membership, MembershipKind},
+    operators::{Aggregation, Complement, SNorm, TNorm},
+    rule::{Expression, Rule},
+    term::Term,
+    variable::{LinguisticVariable, Range},
+};
+
+fn tipper() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("tipper");
+
+    let mut service = LinguisticVariable::new(
+        "service",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    service
+        .add_term(Term::new(
+            "poor",
+            MembershipKind::Gauss {
+                sigma: 1.5,
+                mu: 0.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "good",
+            MembershipKind::Gauss {
+                sigma: 1.5,
+                mu: 5.0,
+            },
+        ))
+        .unwrap();
+    service
+        .add_term(Term::new(
+            "excellent",
+            MembershipKind::Gauss {
+                sigma: 1.5,
+                mu: 10.0,
+            },
+        ))
+        .unwrap();
+    fis.add_input(service);
+
+    let mut food = LinguisticVariable::new(
+        "food",
+        Range {
+            min: 0.0,
+            max: 10.0,
+        },
+    );
+    food.add_term(Term::new(
+        "rancid",
+        MembershipKind::Trapezoid {
+            a: 0.0,
+            b: 0.0,
+            c: 1.0,
+            d: 3.0,
+        },
+    ))
+    .unwrap();
+    food.add_term(Term::new(
+        "delicious",
+        MembershipKind::Trapezoid {
+            a: 7.0,
+            b: 9.0,
+            c: 10.0,
+            d: 10.0,
+        },
+    ))
+    .unwrap();
+    fis.add_input(food);
+
+    let mut tip = LinguisticVariable::new(
+        "tip",
+        Range {
+            min: 0.0,
+            max: 30.0,
+        },
+    );
+    tip.add_term(Term::new(
+        "cheap",
+        MembershipKind::Triangle {
+            a: 0.0,
+            b: 5.0,
+            c: 10.0,
+        },
+    ))
+    .unwrap();
+    tip.add_term(Term::new(
+        "average",
+        MembershipKind::Triangle {
+            a: 10.0,
+            b: 15.0,
+            c: 20.0,
+        },
+    ))
+    .unwrap();
+    tip.add_term(Term::new(
+        "generous",
+        MembershipKind::Triangle {
+            a: 20.0,
+            b: 25.0,
+            c: 30.0,
+        },
+    ))
+    .unwrap();
+    tip.set_defuzzifier(Defuzzifier::Bisector);
+    fis.add_output(tip);
+
+    fis.add_rule_str("IF service IS poor OR food IS rancid THEN tip IS cheap")
+        .unwrap();
+    fis.add_rule_str("IF service IS good THEN tip IS average WITH 0.8")
+        .unwrap();
+    fis.add_rule_str("IF service IS excellent AND food IS NOT rancid THEN tip IS generous")
+        .unwrap();
+    fis.rules.push(Rule::from_expression(
+        Expression::or(vec![
+            Expression::is("service", "excellent"),
+            !Expression::is("food", "rancid"),
+        ]),
+        vec!["generous".into()],
+    ));
+
+    fis.set_and_method(TNorm::Hamacher(0.5));
+    fis.set_or_method(SNorm::ProbabilisticSum);
+    fis.set_aggregation(Aggregation::BoundedSum);
+    fis.set_complement(Complement::Yager(2.0));
+    fis
+}
+
+fn assert_same_outputs(a: &FuzzyInferenceSystem, b: &FuzzyInferenceSystem) {
+    for inputs in [[1.0, 2.0], [5.0, 5.0], [8.5, 9.0], [3.2, 7.7]] {
+        assert_eq!(
+            a.compute(FisType::Mamdani, &inputs).unwrap(),
+            b.compute(FisType::Mamdani, &inputs).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_json_round_trip() {
+    let fis = tipper();
+    let json = serde_json::to_string_pretty(&fis).unwrap();
+    let restored: FuzzyInferenceSystem = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.name, "tipper");
+    assert_eq!(restored.rules.len(), 4);
+    assert_eq!(
+        restored.rules[1].antecedent,
+        vec![Some("good".into()), None]
+    );
+    assert_eq!(restored.rules[1].weight, 0.8);
+    assert!(restored.rules[2].is_negated(1));
+    assert!(restored.rules[3].expression.is_some());
+    assert_eq!(restored.and_method, TNorm::Hamacher(0.5));
+    assert_eq!(restored.complement, Complement::Yager(2.0));
+    assert!(matches!(
+        restored.outputs[0].defuzzifier,
+        Some(Defuzzifier::Bisector)
+    ));
+    assert_same_outputs(&fis, &restored);
+
+    assert_eq!(serde_json::to_string_pretty(&restored).unwrap(), json);
+}
+
+#[test]
+fn test_toml_round_trip() {
+    let fis = tipper();
+    let text = toml::to_string(&fis).unwrap();
+    let restored: FuzzyInferenceSystem = toml::from_str(&text).unwrap();
+
+    assert_eq!(
+        restored.rules[1].antecedent,
+        vec![Some("good".into()), None]
+    );
+    assert_same_outputs(&fis, &restored);
+}
+
+#[test]
+fn test_json_schema_defaults() {
+    let json = r#"{
+        "name": "heater",
+        "inputs": [
+            {
+                "name": "temperature",
+                "range": { "min": 0.0, "max": 40.0 },
+                "terms": [
+                    { "name": "cold", "kind": { "type": "Trapezoid", "a": 0.0, "b": 0.0, "c": 10.0, "d": 20.0 } },
+                    { "name": "hot", "kind": { "type": "Trapezoid", "a": 20.0, "b": 30.0, "c": 40.0, "d": 40.0 } }
+                ]
+            }
+        ],
+        "outputs": [
+            {
+                "name": "power",
+                "range": { "min": 0.0, "max": 100.0 },
+                "terms": [
+                    { "name": "low", "kind": { "type": "Constant", "value": 10.0 } },
+                    { "name": "high", "kind": { "type": "Constant", "value": 90.0 } }
+                ]
+            }
+        ],
+        "rules": [
+            { "antecedent": ["cold"], "consequent": ["high"] },
+            { "antecedent": ["hot"], "consequent": ["low"], "weight": 0.5 },
+            { "antecedent": [null], "consequent": [""] }
+        ]
+    }"#;
+    let fis: FuzzyInferenceSystem = serde_json::from_str(json).unwrap();
+
+    assert_eq!(fis.resolution, 200);
+    assert_eq!(fis.and_method, TNorm::Min);
+    assert!(matches!(fis.defuzzifier, Defuzzifier::Centroid));
+    assert_eq!(fis.rules[0].weight, 1.0);
+    assert_eq!(fis.rules[1].weight, 0.5);
+    assert_eq!(fis.rules[2].antecedent, vec![None]);
+
+    let result = fis.compute(FisType::Sugeno, &[5.0]).unwrap();
+    assert!((result[0] - 90.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_custom_defuzzifier_is_not_serializable() {
+    let mut fis = tipper();
+    fis.set_defuzzifier(Defuzzifier::custom(|xs, _| xs[0]));
+
+    assert!(serde_json::to_string(&fis).is_err());
+}
+
+#[derive(Debug)]
+struct Flat;
+
+impl Membership for Flat {
+    fn degree(&self, _x: f64) -> f64 {
+        1.0
+    }
+
+    fn support(&self) -> Range {
+        Range {
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+        }
+    }
+
+    fn core(&self) -> Range {
+        self.support()
+    }
+}
+
+#[test]
+fn test_custom_membership_is_not_serializable() {
+    let mut fis = tipper();
+    fis.inputs[0].terms[0].kind = MembershipKind::custom(Flat);
+
+    assert!(serde_json::to_string(&fis).is_err());
+}
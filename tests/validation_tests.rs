@@ -0,0 +1,401 @@
+mod common;
+
+use common::variable;
+use fuzzy_logic_engine_rs::{
+    fis::FuzzyInferenceSystem,
+    membership::{Membership, MembershipKind},
+    rule::{Connective, Expression, Rule},
+    term::Term,
+    validation::Diagnostic,
+    variable::Range,
+};
+
+fn heater() -> FuzzyInferenceSystem {
+    let mut fis = FuzzyInferenceSystem::new("heater");
+    fis.add_input(variable(
+        "temperature",
+        0.0,
+        40.0,
+        vec![
+            (
+                "cold",
+                MembershipKind::Trapezoid {
+                    a: 0.0,
+                    b: 0.0,
+                    c: 10.0,
+                    d: 20.0,
+                },
+            ),
+            (
+                "hot",
+                MembershipKind::Triangle {
+                    a: 20.0,
+                    b: 40.0,
+                    c: 40.0,
+                },
+            ),
+        ],
+    ));
+    fis.add_output(variable(
+        "power",
+        0.0,
+        100.0,
+        vec![
+            (
+                "low",
+                MembershipKind::Gauss {
+                    sigma: 10.0,
+                    mu: 0.0,
+                },
+            ),
+            (
+                "high",
+                MembershipKind::Gauss {
+                    sigma: 10.0,
+                    mu: 100.0,
+                },
+            ),
+        ],
+    ));
+    fis.add_rule_str("IF temperature IS cold THEN power IS high")
+        .unwrap();
+    fis.add_rule_str("IF temperature IS NOT cold THEN power IS low WITH 0.5")
+        .unwrap();
+    fis
+}
+
+#[test]
+fn test_valid_system_has_no_diagnostics() {
+    assert!(heater().validate().is_empty());
+}
+
+#[test]
+fn test_reports_all_problems_at_once() {
+    let mut fis = heater();
+    fis.inputs[0].range = Range {
+        min: 40.0,
+        max: 0.0,
+    };
+    fis.inputs[0].terms[1].kind = MembershipKind::Triangle {
+        a: 30.0,
+        b: 20.0,
+        c: 40.0,
+    };
+    fis.outputs[0].terms[0].kind = MembershipKind::Gauss {
+        sigma: 0.0,
+        mu: 0.0,
+    };
+    fis.rules.push(Rule::new(
+        vec![Some("warm".into()), None],
+        vec!["high".into(), "low".into()],
+        Connective::And,
+    ));
+    fis.rules[0].weight = 1.5;
+
+    assert_eq!(
+        fis.validate(),
+        vec![
+            Diagnostic::InvalidRange {
+                variable: "temperature".into(),
+                min: 40.0,
+                max: 0.0,
+            },
+            Diagnostic::UnorderedParameters {
+                variable: "temperature".into(),
+                term: "hot".into(),
+            },
+            Diagnostic::NonPositiveSigma {
+                variable: "power".into(),
+                term: "low".into(),
+                sigma: 0.0,
+            },
+            Diagnostic::InvalidWeight {
+                rule: 0,
+                weight: 1.5,
+            },
+            Diagnostic::AntecedentArity {
+                rule: 2,
+                expected: 1,
+                got: 2,
+            },
+            Diagnostic::UnknownTerm {
+                rule: 2,
+                variable: "temperature".into(),
+                term: "warm".into(),
+            },
+            Diagnostic::ConsequentArity {
+                rule: 2,
+                expected: 1,
+                got: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_zero_width_and_duplicate_terms() {
+    let mut fis = heater();
+    fis.inputs[0].terms.push(Term::new(
+        "cold",
+        MembershipKind::Trapezoid {
+            a: 5.0,
+            b: 5.0,
+            c: 5.0,
+            d: 5.0,
+        },
+    ));
+
+    assert_eq!(
+        fis.validate(),
+        vec![
+            Diagnostic::DuplicateTerm {
+                variable: "temperature".into(),
+                term: "cold".into(),
+            },
+            Diagnostic::ZeroWidth {
+                variable: "temperature".into(),
+                term: "cold".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_curved_shape_parameters() {
+    let mut fis = heater();
+    fis.outputs[0].terms = vec![
+        Term::new(
+            "flat",
+            MembershipKind::Bell {
+                a: 0.0,
+                b: -1.0,
+                c: 50.0,
+            },
+        ),
+        Term::new(
+            "level",
+            MembershipKind::DiffSigmoid {
+                a1: 2.0,
+                c1: 20.0,
+                a2: 0.0,
+                c2: 80.0,
+            },
+        ),
+        Term::new("step", MembershipKind::SShape { a: 60.0, b: 40.0 }),
+        Term::new(
+            "wide",
+            MembershipKind::Gauss2 {
+                sigma1: 10.0,
+                mu1: 40.0,
+                sigma2: 0.0,
+                mu2: 60.0,
+            },
+        ),
+    ];
+    fis.rules.clear();
+
+    assert_eq!(
+        fis.validate(),
+        vec![
+            Diagnostic::ZeroWidth {
+                variable: "power".into(),
+                term: "flat".into(),
+            },
+            Diagnostic::NonPositiveSlope {
+                variable: "power".into(),
+                term: "flat".into(),
+                slope: -1.0,
+            },
+            Diagnostic::ZeroSlope {
+                variable: "power".into(),
+                term: "level".into(),
+            },
+            Diagnostic::UnorderedParameters {
+                variable: "power".into(),
+                term: "step".into(),
+            },
+            Diagnostic::NonPositiveSigma {
+                variable: "power".into(),
+                term: "wide".into(),
+                sigma: 0.0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_shoulder_parameters() {
+    let mut fis = heater();
+    fis.outputs[0].terms = vec![
+        // a vertical edge is a crisp step, not a zero width term
+        Term::new("off", MembershipKind::LeftShoulder { a: 20.0, b: 20.0 }),
+        Term::new("full", MembershipKind::RightShoulder { a: 80.0, b: 60.0 }),
+    ];
+    fis.rules.clear();
+
+    assert_eq!(
+        fis.validate(),
+        vec![Diagnostic::UnorderedParameters {
+            variable: "power".into(),
+            term: "full".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_point_list_parameters() {
+    let mut fis = heater();
+    fis.outputs[0].terms = vec![
+        Term::new(
+            "curve",
+            MembershipKind::Polygon {
+                points: vec![(0.0, 0.0), (30.0, 1.2), (20.0, 0.5)],
+            },
+        ),
+        Term::new(
+            "measured",
+            MembershipKind::Sampled {
+                min: 0.0,
+                max: 100.0,
+                values: vec![0.0, 0.3, -0.1, 1.0],
+            },
+        ),
+        Term::new(
+            "empty",
+            MembershipKind::Sampled {
+                min: 0.0,
+                max: 100.0,
+                values: vec![],
+            },
+        ),
+        Term::new(
+            "valid",
+            MembershipKind::Polygon {
+                points: vec![(40.0, 0.0), (50.0, 1.0), (50.0, 0.5), (80.0, 0.0)],
+            },
+        ),
+    ];
+    fis.rules.clear();
+
+    assert_eq!(
+        fis.validate(),
+        vec![
+            Diagnostic::UnorderedParameters {
+                variable: "power".into(),
+                term: "curve".into(),
+            },
+            Diagnostic::DegreeOutOfRange {
+                variable: "power".into(),
+                term: "curve".into(),
+                degree: 1.2,
+            },
+            Diagnostic::DegreeOutOfRange {
+                variable: "power".into(),
+                term: "measured".into(),
+                degree: -0.1,
+            },
+            Diagnostic::ZeroWidth {
+                variable: "power".into(),
+                term: "empty".into(),
+            },
+        ]
+    );
+}
+
+// a core reaching beyond its support
+#[derive(Debug)]
+struct Inconsistent;
+
+impl Membership for Inconsistent {
+    fn degree(&self, _x: f64) -> f64 {
+        1.0
+    }
+
+    fn support(&self) -> Range {
+        Range {
+            min: 10.0,
+            max: 20.0,
+        }
+    }
+
+    fn core(&self) -> Range {
+        Range {
+            min: 15.0,
+            max: 25.0,
+        }
+    }
+}
+
+#[test]
+fn test_custom_membership_core_within_support() {
+    let mut fis = heater();
+    fis.outputs[0].terms = vec![Term::new("odd", MembershipKind::custom(Inconsistent))];
+    fis.rules.clear();
+
+    assert_eq!(
+        fis.validate(),
+        vec![Diagnostic::UnorderedParameters {
+            variable: "power".into(),
+            term: "odd".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_expression_rules_are_resolved_by_name() {
+    let mut fis = heater();
+    fis.rules.push(Rule::from_expression(
+        Expression::or(vec![
+            Expression::is("humidity", "high"),
+            !Expression::is("temperature", "mild"),
+        ]),
+        vec!["unknown".into()],
+    ));
+
+    assert_eq!(
+        fis.validate(),
+        vec![
+            Diagnostic::UnknownVariable {
+                rule: 2,
+                variable: "humidity".into(),
+            },
+            Diagnostic::UnknownTerm {
+                rule: 2,
+                variable: "temperature".into(),
+                term: "mild".into(),
+            },
+            Diagnostic::UnknownTerm {
+                rule: 2,
+                variable: "power".into(),
+                term: "unknown".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_linear_consequent_arity() {
+    let mut fis = heater();
+    fis.outputs[0].terms.push(Term::new(
+        "ramp",
+        MembershipKind::Linear {
+            coefficients: vec![1.0, 2.0],
+            constant: 0.0,
+        },
+    ));
+
+    let diagnostics = fis.validate();
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::LinearArity {
+            variable: "power".into(),
+            term: "ramp".into(),
+            expected: 1,
+            got: 2,
+        }]
+    );
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "variable power, term ramp: expected 1 linear coefficients, got 2"
+    );
+}
@@ -6,7 +6,7 @@ use fuzzy_logic_engine_rs::{
 
 #[test]
 fn test_add_and_verify_and_find_term() {
-    let mut var = LinguisticVariable::new(
+    let mut var: LinguisticVariable = LinguisticVariable::new(
         "temperature",
         Range {
             min: 0.0,
@@ -20,7 +20,8 @@ fn test_add_and_verify_and_find_term() {
             b: 0.0,
             c: 50.0,
         },
-    ));
+    ))
+    .unwrap();
     var.add_term(Term::new(
         "hot",
         MembershipKind::Triangle {
@@ -28,7 +29,8 @@ fn test_add_and_verify_and_find_term() {
             b: 100.0,
             c: 100.0,
         },
-    ));
+    ))
+    .unwrap();
 
     let cold = var.term("cold").unwrap();
     assert!((cold.degree(25.0) - 0.5).abs() < 1e-6);